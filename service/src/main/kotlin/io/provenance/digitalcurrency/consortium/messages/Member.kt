@@ -12,4 +12,7 @@ data class MemberResponse(
 )
 
 @JsonIgnoreProperties(ignoreUnknown = true)
-data class MemberListResponse(val members: List<MemberResponse>)
+data class MemberListResponse(
+    val members: List<MemberResponse>,
+    @JsonProperty("next_key") val nextKey: String? = null,
+)
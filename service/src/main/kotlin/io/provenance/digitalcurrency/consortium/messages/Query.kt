@@ -18,9 +18,15 @@ class EmptyObject
 
 interface JsonI
 
+@JsonInclude(Include.NON_NULL)
+data class PageRequest(
+    @JsonProperty("start_after") val startAfter: String? = null,
+    val limit: Int? = null,
+)
+
 @JsonInclude(Include.NON_NULL)
 class QueryRequest(
-    @JsonProperty("get_members") val getMembers: EmptyObject? = null,
+    @JsonProperty("get_members") val getMembers: PageRequest? = null,
 ) : JsonI
 
 internal val mapper = ObjectMapper()
@@ -24,8 +24,9 @@ import io.provenance.digitalcurrency.consortium.extension.throwIfFailed
 import io.provenance.digitalcurrency.consortium.extension.toAny
 import io.provenance.digitalcurrency.consortium.extension.toProtoTimestamp
 import io.provenance.digitalcurrency.consortium.extension.toTxBody
-import io.provenance.digitalcurrency.consortium.messages.EmptyObject
 import io.provenance.digitalcurrency.consortium.messages.MemberListResponse
+import io.provenance.digitalcurrency.consortium.messages.MemberResponse
+import io.provenance.digitalcurrency.consortium.messages.PageRequest
 import io.provenance.digitalcurrency.consortium.messages.QueryRequest
 import io.provenance.digitalcurrency.consortium.messages.toByteString
 import io.provenance.digitalcurrency.consortium.messages.toValueResponse
@@ -37,6 +38,8 @@ import tech.figure.eventstream.stream.models.BlockResponse
 import java.time.OffsetDateTime
 import javax.annotation.PreDestroy
 
+private const val MEMBER_PAGE_LIMIT = 30
+
 @Service
 class PbcService(
     private val pbClient: PbClient,
@@ -129,12 +132,29 @@ class PbcService(
             gasAdjustment = provenanceProperties.gasAdjustment * 1.1,
         ).throwIfFailed("Marker transfer authorization grant authz failed")
 
-    fun getMembers(): MemberListResponse =
+    fun getMembers(): MemberListResponse {
+        val members = mutableListOf<MemberResponse>()
+        var startAfter: String? = null
+
+        // page through members until the contract stops returning a next key
+        do {
+            val page = getMembersPage(startAfter)
+            members.addAll(page.members)
+            startAfter = page.nextKey
+        } while (startAfter != null)
+
+        return MemberListResponse(members)
+    }
+
+    private fun getMembersPage(startAfter: String?): MemberListResponse =
         pbClient.wasmClient
             .queryWasm(
                 QuerySmartContractStateRequest.newBuilder()
                     .setAddress(provenanceProperties.contractAddress)
-                    .setQueryData(QueryRequest(getMembers = EmptyObject()).toByteString())
+                    .setQueryData(
+                        QueryRequest(getMembers = PageRequest(startAfter = startAfter, limit = MEMBER_PAGE_LIMIT))
+                            .toByteString(),
+                    )
                     .build(),
             )
             .toValueResponse(MemberListResponse::class)
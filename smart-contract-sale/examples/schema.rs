@@ -3,7 +3,7 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
-use dcc_sale::msg::{ExecuteMsg, InitMsg, QueryMsg};
+use dcc_sale::msg::{ExecuteMsg, InitMsg, QueryMsg, Sales};
 use dcc_sale::state::{Sale, State};
 
 fn main() {
@@ -16,5 +16,6 @@ fn main() {
     export_schema(&schema_for!(InitMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
     export_schema(&schema_for!(Sale), &out_dir);
+    export_schema(&schema_for!(Sales), &out_dir);
     export_schema(&schema_for!(State), &out_dir);
 }
@@ -1,73 +1,206 @@
 use std::convert::TryFrom;
 
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
 use cosmwasm_std::{
-    attr, entry_point, to_binary, BankMsg, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo,
-    Response, StdError, StdResult, WasmMsg,
+    attr, entry_point, to_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut,
+    Empty, Env, MessageInfo, Order, Response, StdError, StdResult, Uint128, WasmMsg,
 };
+use cosmwasm_storage::ReadonlyBucket;
 use cw2::get_contract_version;
-use provwasm_std::types::provenance::marker::v1::{MarkerAccount, MarkerQuerier};
+use provwasm_std::types::cosmos::base::v1beta1::Coin as ProvCoin;
+use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
+use provwasm_std::types::provenance::marker::v1::{
+    Access, MarkerAccount, MarkerQuerier, MarkerType, MsgTransferRequest,
+};
 
 use crate::error::{contract_err, ContractError};
-use crate::msg::{DCCWasmMsg, ExecuteMsg, QueryMsg, Validate};
-use crate::state::{config_read, get_sale_storage, get_sale_storage_read, Sale, Status};
+use crate::msg::{DCCWasmMsg, ExecuteMsg, QueryMsg, Sales, Validate};
+use crate::state::{
+    config, config_read, get_sale_storage_read, sales_by_buyer, sales_by_buyer_read,
+    sales_by_owner_read, sales_by_status_read, save_sale, Expiration, FeeConfig, PendingAdmin,
+    Sale, Status,
+};
 
 pub const CRATE_NAME: &str = env!("CARGO_CRATE_NAME");
 pub const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 30;
 
 // smart contract execute entrypoint
 #[entry_point]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     msg.validate()?;
 
     match msg {
-        ExecuteMsg::CreateSale { id, buyer, price } => create_sale(deps, info, id, buyer, price),
-        ExecuteMsg::CompleteSale { id } => complete_sale(deps, info, id),
-        ExecuteMsg::CancelSale { id } => cancel_sale(deps, info, id),
+        ExecuteMsg::CreateSale {
+            id,
+            buyer,
+            allowed_buyers,
+            price,
+            quantity,
+            expires_at,
+            asset,
+        } => create_sale(
+            deps,
+            env,
+            info,
+            id,
+            buyer,
+            allowed_buyers,
+            price,
+            quantity,
+            expires_at,
+            asset,
+        ),
+        ExecuteMsg::FundSale { id } => fund_sale(deps, env, info, id),
+        ExecuteMsg::UpdateSale { id, price, buyer } => {
+            update_sale(deps, env, info, id, price, buyer)
+        }
+        ExecuteMsg::CompleteSale {
+            id,
+            quantity,
+            expected_version,
+            expected_price,
+        } => complete_sale(
+            deps,
+            env,
+            info,
+            id,
+            quantity,
+            expected_version,
+            expected_price,
+        ),
+        ExecuteMsg::CancelSale { id } => cancel_sale(deps, env, info, id),
+        ExecuteMsg::RejectSale { id, reason } => reject_sale(deps, env, info, id, reason),
+        ExecuteMsg::ReclaimExpired { id } => reclaim_expired(deps, env, info, id),
+        ExecuteMsg::ProposeAdmin { id, expires_at } => {
+            propose_admin(deps, env, info, id, expires_at)
+        }
+        ExecuteMsg::AcceptAdmin {} => accept_admin(deps, env, info),
+        ExecuteMsg::CancelAdminProposal {} => cancel_admin_proposal(deps, info),
+        ExecuteMsg::UpdateFeeConfig {
+            collector,
+            bps,
+            min_fee,
+        } => update_fee_config(deps, info, collector, bps, min_fee),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_sale(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     id: String,
-    buyer: String,
+    buyer: Option<String>,
+    allowed_buyers: Option<Vec<String>>,
     price: Coin,
+    quantity: Option<Uint128>,
+    expires_at: Option<Expiration>,
+    restricted_asset: Option<Vec<Coin>>,
 ) -> Result<Response, ContractError> {
     // validate params
-    if info.funds.is_empty() {
-        return Err(contract_err("funds should be sent during sale creation"));
-    }
+    let restricted = restricted_asset.is_some();
+    let asset = match restricted_asset {
+        Some(asset) => {
+            // restricted assets are transferred by the contract, not sent
+            if !info.funds.is_empty() {
+                return Err(contract_err(
+                    "no funds should be sent with a restricted asset",
+                ));
+            }
+            asset
+        }
+        None => {
+            if info.funds.is_empty() {
+                return Err(contract_err("funds should be sent during sale creation"));
+            }
+            info.funds
+        }
+    };
 
-    // ensure only single asset is sent
-    if info.funds.len() > 1 {
-        return Err(contract_err(
-            "only one fund should be sent during sale creation",
-        ));
+    // ensure each denom is in the basket once
+    for (i, coin) in asset.iter().enumerate() {
+        if asset[..i].iter().any(|other| other.denom == coin.denom) {
+            return Err(contract_err(&format!(
+                "asset denom {} should only be sent once",
+                coin.denom
+            )));
+        }
     }
 
-    if buyer == info.sender {
+    // an open offer has no buyer, and can be limited to allowed buyers
+    let buyer = buyer
+        .map(|buyer| deps.api.addr_validate(&buyer))
+        .transpose()?;
+    let allowed_buyers = allowed_buyers
+        .unwrap_or_default()
+        .iter()
+        .map(|buyer| deps.api.addr_validate(buyer))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    if buyer.as_ref() == Some(&info.sender) || allowed_buyers.contains(&info.sender) {
         return Err(contract_err("owner cannot also be the buyer"));
     }
 
-    // only unrestricted markers are supported
-    let asset = info.funds.first().unwrap();
+    // ensure the asset and price split evenly into the quantity
+    let quantity = quantity.unwrap_or_else(Uint128::one);
+    if asset.iter().any(|coin| !(coin.amount % quantity).is_zero())
+        || !(price.amount % quantity).is_zero()
+    {
+        return Err(contract_err(
+            "asset and price amounts must be divisible by quantity",
+        ));
+    }
+
     let querier = MarkerQuerier::new(&deps.querier);
-    let is_unrestricted = matches!(
-        get_marker(asset.denom.clone(), &querier),
-        Ok(MarkerAccount {
-            marker_type: 1, // 1 index is coin
-            ..
-        })
-    );
-    if !is_unrestricted {
-        return Err(ContractError::UnsupportedMarkerType {
-            error: String::from("fund marker type must be unrestricted"),
-        });
+    for coin in asset.iter() {
+        let marker = get_marker(coin.denom.clone(), &querier).ok();
+        if restricted {
+            // the contract must be able to transfer the restricted asset
+            let marker = match marker {
+                Some(marker) if marker.marker_type == MarkerType::Restricted as i32 => marker,
+                _ => {
+                    return Err(ContractError::UnsupportedMarkerType {
+                        error: String::from("asset marker type must be restricted"),
+                    })
+                }
+            };
+            let has_transfer = marker.access_control.iter().any(|grant| {
+                grant.address == env.contract.address.as_str()
+                    && grant.permissions.contains(&(Access::Transfer as i32))
+            });
+            if !has_transfer {
+                return Err(ContractError::UnsupportedMarkerType {
+                    error: String::from("contract must have transfer access on asset marker"),
+                });
+            }
+
+            // ensure known buyers can hold the asset so completion cannot fail
+            for buyer in buyer.iter().chain(allowed_buyers.iter()) {
+                ensure_required_attributes(deps.as_ref(), &marker, buyer)?;
+            }
+        } else {
+            // only unrestricted markers can be sent as funds
+            let is_unrestricted = matches!(
+                marker,
+                Some(MarkerAccount {
+                    marker_type: 1, // 1 index is coin
+                    ..
+                })
+            );
+            if !is_unrestricted {
+                return Err(ContractError::UnsupportedMarkerType {
+                    error: String::from("fund marker type must be unrestricted"),
+                });
+            }
+        }
     }
 
     // ensure price denom is the dcc denom
@@ -79,6 +212,13 @@ fn create_sale(
         )));
     }
 
+    // ensure expiry is in the future
+    if let Some(expires_at) = &expires_at {
+        if expires_at.is_expired(&env.block) {
+            return Err(contract_err("sale expiry must be in the future"));
+        }
+    }
+
     // dupe check
     if get_sale_storage_read(deps.storage)
         .may_load(id.as_bytes())?
@@ -89,35 +229,59 @@ fn create_sale(
 
     let sale = Sale {
         id,
-        asset: asset.clone(),
+        asset,
         owner: info.sender,
-        buyer: deps.api.addr_validate(&buyer)?,
+        buyer,
         price,
         status: Status::Pending,
+        funded: false,
+        expires_at,
+        restricted,
+        allowed_buyers,
+        quantity,
+        remaining: quantity,
+        version: 0,
     };
 
-    get_sale_storage(deps.storage).save(sale.id.as_bytes(), &sale)?;
+    save_sale(deps.storage, &sale)?;
 
-    let response = Response::new().add_attributes(vec![
-        attr("action", "create_sale"),
-        attr("owner", sale.owner),
-        attr("buyer", sale.buyer),
-        attr("asset_denom", sale.asset.denom),
-        attr("asset_amount", sale.asset.amount),
-        attr("price_denom", sale.price.denom),
-        attr("price_amount", sale.price.amount),
-        attr("id", sale.id),
-    ]);
+    let mut response = Response::new();
+
+    // take custody of restricted asset
+    if sale.restricted {
+        for coin in sale.asset.iter() {
+            response = response.add_message(MsgTransferRequest {
+                amount: Some(ProvCoin {
+                    denom: coin.denom.clone(),
+                    amount: coin.amount.to_string(),
+                }),
+                administrator: env.contract.address.to_string(),
+                from_address: sale.owner.to_string(),
+                to_address: env.contract.address.to_string(),
+            });
+        }
+    }
+
+    response = response.add_attributes(sale_attributes(
+        "create_sale",
+        &sale,
+        sale.buyer.as_ref(),
+        &sale.asset,
+        &sale.price,
+    ));
 
     Ok(response)
 }
 
-fn complete_sale(deps: DepsMut, info: MessageInfo, id: String) -> Result<Response, ContractError> {
+fn fund_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response, ContractError> {
     // validate params
     if !info.funds.is_empty() {
-        return Err(contract_err(
-            "no funds should be sent during sale completion",
-        ));
+        return Err(contract_err("no funds should be sent during sale funding"));
     }
 
     // ensure existence
@@ -125,65 +289,62 @@ fn complete_sale(deps: DepsMut, info: MessageInfo, id: String) -> Result<Respons
         .load(id.as_bytes())
         .map_err(ContractError::Std)?;
 
-    // ensure pending
-    if sale.status != Status::Pending {
-        return Err(contract_err(&format!(
-            "sale is in invalid state {}",
-            sale.status,
-        )));
-    }
+    ensure_open(&sale)?;
+    ensure_not_expired(&env, &sale)?;
 
     // ensure buyer is sender
-    if sale.buyer != info.sender {
+    if sale.buyer.as_ref() != Some(&info.sender) {
         return Err(ContractError::Unauthorized {
-            error: String::from("only buyer can complete sale"),
+            error: String::from("only buyer can fund sale"),
         });
     }
 
+    if sale.funded {
+        return Err(contract_err("sale is already funded"));
+    }
+
     let state = config_read(deps.storage).load()?;
     let mut response = Response::new();
 
-    // transfer price in dcc token to seller
+    // escrow price of the remaining quantity in dcc token at the contract
+    let (_, price) = sale_portion(&sale, sale.remaining);
     response = response.add_message(WasmMsg::Execute {
         contract_addr: state.dcc_address.into_string(),
         msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
-            amount: sale.price.amount,
-            sender: info.sender.into_string(),
-            recipient: sale.owner.clone().into_string(),
+            amount: price.amount,
+            sender: info.sender.to_string(),
+            recipient: env.contract.address.into_string(),
         })?,
         funds: vec![],
     });
 
-    // transfer asset to buyer
-    response = response.add_message(BankMsg::Send {
-        to_address: sale.buyer.to_string(),
-        amount: vec![sale.asset.clone()],
-    });
-
-    // update status to complete
-    sale.status = Status::Complete;
-    get_sale_storage(deps.storage).save(id.as_bytes(), &sale)?;
+    // update sale to funded
+    sale.funded = true;
+    save_sale(deps.storage, &sale)?;
 
     response = response.add_attributes(vec![
-        attr("action", "complete_sale"),
+        attr("action", "fund_sale"),
         attr("owner", sale.owner),
-        attr("buyer", sale.buyer),
-        attr("asset_denom", sale.asset.denom),
-        attr("asset_amount", sale.asset.amount),
-        attr("price_denom", sale.price.denom),
-        attr("price_amount", sale.price.amount),
+        attr("buyer", info.sender),
+        attr("price_denom", price.denom),
+        attr("price_amount", price.amount),
         attr("id", sale.id),
     ]);
 
     Ok(response)
 }
 
-fn cancel_sale(deps: DepsMut, info: MessageInfo, id: String) -> Result<Response, ContractError> {
+fn update_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    price: Coin,
+    buyer: Option<String>,
+) -> Result<Response, ContractError> {
     // validate params
     if !info.funds.is_empty() {
-        return Err(contract_err(
-            "no funds should be sent during sale cancellation",
-        ));
+        return Err(contract_err("no funds should be sent during sale update"));
     }
 
     // ensure existence
@@ -191,346 +352,2724 @@ fn cancel_sale(deps: DepsMut, info: MessageInfo, id: String) -> Result<Response,
         .load(id.as_bytes())
         .map_err(ContractError::Std)?;
 
-    // ensure pending
+    // ensure owner is sender
+    if sale.owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            error: String::from("only owner can update sale"),
+        });
+    }
+
+    // only unfilled sales can be amended
     if sale.status != Status::Pending {
         return Err(contract_err(&format!(
             "sale is in invalid state {}",
-            sale.status,
+            sale.status
         )));
     }
+    ensure_not_expired(&env, &sale)?;
 
-    // ensure owner is sender
-    if sale.owner != info.sender {
-        return Err(ContractError::Unauthorized {
-            error: String::from("only owner can cancel sale"),
-        });
+    // the escrowed price is bound to the funded terms
+    if sale.funded {
+        return Err(contract_err("funded sale cannot be updated"));
     }
 
-    let mut response = Response::new();
+    // ensure price denom is the dcc denom
+    let state = config_read(deps.storage).load()?;
+    if price.denom != state.dcc_denom {
+        return Err(contract_err(&format!(
+            "price denom must be {}",
+            state.dcc_denom
+        )));
+    }
 
-    // return asset back to owner
-    response = response.add_message(BankMsg::Send {
-        to_address: sale.owner.to_string(),
-        amount: vec![sale.asset.clone()],
-    });
+    // ensure the price splits evenly into the quantity
+    if !(price.amount % sale.quantity).is_zero() {
+        return Err(contract_err(
+            "asset and price amounts must be divisible by quantity",
+        ));
+    }
 
-    // update status to canceled
-    sale.status = Status::Canceled;
-    get_sale_storage(deps.storage).save(id.as_bytes(), &sale)?;
+    if let Some(buyer) = buyer {
+        let buyer = deps.api.addr_validate(&buyer)?;
+        if buyer == sale.owner {
+            return Err(contract_err("owner cannot also be the buyer"));
+        }
+        if !sale.allowed_buyers.is_empty() {
+            return Err(contract_err(
+                "buyer cannot be set on a sale with allowed buyers",
+            ));
+        }
 
-    response = response.add_attributes(vec![
-        attr("action", "cancel_sale"),
-        attr("owner", sale.owner),
-        attr("buyer", sale.buyer),
-        attr("asset_denom", sale.asset.denom),
-        attr("asset_amount", sale.asset.amount),
-        attr("price_denom", sale.price.denom),
-        attr("price_amount", sale.price.amount),
-        attr("id", sale.id),
-    ]);
+        // ensure the new buyer can hold the restricted asset
+        if sale.restricted {
+            let querier = MarkerQuerier::new(&deps.querier);
+            for coin in sale.asset.iter() {
+                let marker = get_marker(coin.denom.clone(), &querier)?;
+                ensure_required_attributes(deps.as_ref(), &marker, &buyer)?;
+            }
+        }
+
+        sale.buyer = Some(buyer);
+    }
+
+    sale.price = price;
+    sale.version += 1;
+    save_sale(deps.storage, &sale)?;
+
+    let response = Response::new()
+        .add_attributes(sale_attributes(
+            "update_sale",
+            &sale,
+            sale.buyer.as_ref(),
+            &sale.asset,
+            &sale.price,
+        ))
+        .add_attribute("version", sale.version.to_string());
 
     Ok(response)
 }
 
-fn get_marker(id: String, querier: &MarkerQuerier<Empty>) -> StdResult<MarkerAccount> {
-    let response = querier.marker(id)?;
-    if let Some(marker) = response.marker {
-        return if let Ok(account) = MarkerAccount::try_from(marker) {
-            Ok(account)
-        } else {
-            Err(StdError::generic_err("unable to type-cast marker account"))
-        };
-    } else {
-        Err(StdError::generic_err("no marker found for id"))
+fn complete_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    quantity: Option<Uint128>,
+    expected_version: Option<u32>,
+    expected_price: Option<Coin>,
+) -> Result<Response, ContractError> {
+    // validate params
+    if !info.funds.is_empty() {
+        return Err(contract_err(
+            "no funds should be sent during sale completion",
+        ));
     }
-}
 
-#[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    msg.validate()?;
+    // ensure existence
+    let mut sale = get_sale_storage_read(deps.storage)
+        .load(id.as_bytes())
+        .map_err(ContractError::Std)?;
 
-    match msg {
-        QueryMsg::GetSale { id } => {
-            to_binary(&get_sale_storage_read(deps.storage).load(id.as_bytes())?)
+    ensure_open(&sale)?;
+    ensure_not_expired(&env, &sale)?;
+
+    // ensure the terms have not been amended since the buyer read them
+    if let Some(expected_version) = expected_version {
+        if sale.version != expected_version {
+            return Err(contract_err(&format!(
+                "sale version {} does not match expected version {}",
+                sale.version, expected_version
+            )));
+        }
+    }
+    if let Some(expected_price) = expected_price {
+        if sale.price != expected_price {
+            return Err(contract_err(&format!(
+                "sale price {} does not match expected price {}",
+                sale.price, expected_price
+            )));
         }
-        QueryMsg::GetContractInfo {} => to_binary(&config_read(deps.storage).load()?),
-        QueryMsg::GetVersionInfo {} => to_binary(&get_contract_version(deps.storage)?),
     }
-}
 
-// unit tests
-#[cfg(test)]
-mod tests {
-    use crate::contract::execute;
-    use crate::msg::ExecuteMsg;
-    use crate::state::{config, get_sale_storage, get_sale_storage_read, Sale, State, Status};
-    use crate::ContractError;
-    use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{attr, Addr, Coin, StdError, Storage, Uint128};
-    use prost::Message;
-    use provwasm_mocks::mock_provenance_dependencies;
-    use provwasm_std::shim::Any;
-    use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
-    use provwasm_std::types::provenance::marker::v1::{
-        AccessGrant, MarkerAccount, MarkerStatus, MarkerType, QueryMarkerRequest,
-        QueryMarkerResponse,
+    let buyer = match &sale.buyer {
+        // ensure buyer is sender, or the owner once both legs are escrowed
+        Some(buyer) => {
+            if *buyer != info.sender && !(sale.funded && sale.owner == info.sender) {
+                return Err(ContractError::Unauthorized {
+                    error: String::from("only buyer can complete sale"),
+                });
+            }
+            buyer.clone()
+        }
+        // ensure sender is allowed to buy an open offer
+        None => {
+            if !sale.allowed_buyers.is_empty() && !sale.allowed_buyers.contains(&info.sender) {
+                return Err(ContractError::Unauthorized {
+                    error: String::from("only allowed buyers can complete sale"),
+                });
+            }
+            if sale.owner == info.sender {
+                return Err(contract_err("owner cannot also be the buyer"));
+            }
+            info.sender.clone()
+        }
     };
 
-    const ID: &str = "1bd75dc7-5c15-4ef8-9d8c-de9c106d1fdc";
-    const ADMIN_ADDRESS: &str = "tp1343puvn0uvzrmxkwzkx62z9eay56ag9y49f3af";
-    const OWNER_ADDRESS: &str = "tp1vxyyagf9p7se5rhh9vxs39tswr0srckusjq585";
-    const BUYER_ADDRESS: &str = "tp1gjuyjcgxhmv4d003ja576dmzfeu5pjjex7h5k3";
-    const ASSET_DENOM: &str = "ibc/loan1";
-    const DCC_DENOM: &str = "usdf.c";
-    const DCC_ADDRESS: &str = "tp1u9t9ung76wzw88jhr0pa5szvl6ystdfeey2mrm";
+    // ensure quantity is available, defaulting to the remaining quantity
+    let quantity = quantity.unwrap_or(sale.remaining);
+    if quantity > sale.remaining {
+        return Err(contract_err(&format!(
+            "quantity exceeds remaining quantity {}",
+            sale.remaining
+        )));
+    }
 
-    #[test]
-    fn create_sale_success() {
-        let mut deps = mock_provenance_dependencies();
+    // buyers of a restricted open offer are only known at completion
+    if sale.restricted && sale.buyer.is_none() && sale.allowed_buyers.is_empty() {
+        let querier = MarkerQuerier::new(&deps.querier);
+        for coin in sale.asset.iter() {
+            let marker = get_marker(coin.denom.clone(), &querier)?;
+            ensure_required_attributes(deps.as_ref(), &marker, &buyer)?;
+        }
+    }
 
-        setup_test_base(
-            &mut deps.storage,
-            &State {
-                admin: Addr::unchecked(ADMIN_ADDRESS),
-                dcc_address: Addr::unchecked(DCC_ADDRESS),
-                dcc_denom: DCC_DENOM.into(),
-            },
-        );
+    let state = config_read(deps.storage).load()?;
+    let (asset, price) = sale_portion(&sale, quantity);
+    let mut response = Response::new();
 
-        QueryMarkerRequest::mock_response(&mut deps.querier, setup_asset_marker());
+    // split the marketplace fee from the price
+    let fee = match &state.fee_config {
+        Some(fee_config) => fee_config.fee(price.amount),
+        None => Uint128::zero(),
+    };
+    let mut payments = vec![];
+    // the seller receives nothing when the fee takes the whole price
+    if fee < price.amount {
+        payments.push((price.amount - fee, &sale.owner));
+    }
+    if let (false, Some(fee_config)) = (fee.is_zero(), &state.fee_config) {
+        payments.push((fee, &fee_config.collector));
+    }
 
-        let asset = Coin {
-            amount: Uint128::new(1),
-            denom: ASSET_DENOM.into(),
+    // transfer price in dcc token to seller and fee collector, from escrow if funded
+    for (amount, recipient) in payments {
+        let dcc_msg = if sale.funded {
+            DCCWasmMsg::Transfer {
+                amount,
+                recipient: recipient.to_string(),
+            }
+        } else {
+            DCCWasmMsg::ExecutorTransfer {
+                amount,
+                sender: buyer.to_string(),
+                recipient: recipient.to_string(),
+            }
         };
+        response = response.add_message(WasmMsg::Execute {
+            contract_addr: state.dcc_address.to_string(),
+            msg: to_binary(&dcc_msg)?,
+            funds: vec![],
+        });
+    }
 
-        let sender_info = mock_info(OWNER_ADDRESS, &[asset.clone()]);
+    // transfer asset to buyer
+    response = response.add_messages(asset_transfer_msgs(&env, sale.restricted, &asset, &buyer));
 
-        let price = Coin {
-            amount: Uint128::new(100),
-            denom: DCC_DENOM.into(),
-        };
+    // update status to complete once the sale is filled
+    sale.remaining -= quantity;
+    sale.status = if sale.remaining.is_zero() {
+        Status::Complete
+    } else {
+        Status::PartiallyFilled
+    };
+    save_sale(deps.storage, &sale)?;
 
-        let create_msg = ExecuteMsg::CreateSale {
-            id: ID.into(),
-            price: price.clone(),
-            buyer: BUYER_ADDRESS.into(),
-        };
+    // buyers of an open offer are only known at completion
+    if sale.buyer.is_none() {
+        sales_by_buyer(deps.storage, &buyer).save(sale.id.as_bytes(), &Empty {})?;
+    }
 
-        let create_response = execute(deps.as_mut(), mock_env(), sender_info, create_msg);
+    response = response
+        .add_attributes(sale_attributes(
+            "complete_sale",
+            &sale,
+            Some(&buyer),
+            &asset,
+            &price,
+        ))
+        .add_attribute("quantity", quantity)
+        .add_attribute("remaining", sale.remaining)
+        .add_attribute("fee_amount", fee);
 
-        match create_response {
-            Ok(response) => {
-                assert_eq!(response.attributes.len(), 8);
-                assert_eq!(response.attributes[0], attr("action", "create_sale"));
-                assert_eq!(response.attributes[1], attr("owner", OWNER_ADDRESS));
-                assert_eq!(response.attributes[2], attr("buyer", BUYER_ADDRESS));
-                assert_eq!(response.attributes[3], attr("asset_denom", &asset.denom));
-                assert_eq!(response.attributes[4], attr("asset_amount", asset.amount));
-                assert_eq!(response.attributes[5], attr("price_denom", &price.denom));
-                assert_eq!(response.attributes[6], attr("price_amount", price.amount));
-                assert_eq!(response.attributes[7], attr("id", ID));
-            }
-            Err(error) => {
-                panic!("failed to create asset sale: {:?}", error)
-            }
-        }
+    Ok(response)
+}
 
-        match get_sale_storage_read(&deps.storage).load(ID.as_bytes()) {
-            Ok(sale) => {
-                assert_eq!(
-                    Sale {
-                        id: ID.into(),
-                        asset,
-                        owner: Addr::unchecked(OWNER_ADDRESS),
-                        buyer: Addr::unchecked(BUYER_ADDRESS),
-                        price,
-                        status: Status::Pending,
-                    },
-                    sale
-                );
-            }
-            _ => {
-                panic!("sale not found in storage")
-            }
-        }
+fn cancel_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response, ContractError> {
+    // validate params
+    if !info.funds.is_empty() {
+        return Err(contract_err(
+            "no funds should be sent during sale cancellation",
+        ));
     }
 
-    #[test]
-    fn create_sale_invalid_params() {
-        let mut deps = mock_provenance_dependencies();
+    // ensure existence
+    let sale = get_sale_storage_read(deps.storage)
+        .load(id.as_bytes())
+        .map_err(ContractError::Std)?;
 
-        setup_test_base(
-            &mut deps.storage,
-            &State {
-                admin: Addr::unchecked(ADMIN_ADDRESS),
-                dcc_address: Addr::unchecked(DCC_ADDRESS),
+    ensure_open(&sale)?;
+
+    // ensure owner is sender, or the buyer once it has escrowed the price
+    if sale.owner != info.sender && !(sale.funded && sale.buyer.as_ref() == Some(&info.sender)) {
+        return Err(ContractError::Unauthorized {
+            error: String::from("only owner can cancel sale"),
+        });
+    }
+
+    close_sale(deps, &env, sale, Status::Canceled, "cancel_sale")
+}
+
+fn reject_sale(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    // validate params
+    if !info.funds.is_empty() {
+        return Err(contract_err(
+            "no funds should be sent during sale rejection",
+        ));
+    }
+
+    // ensure existence
+    let sale = get_sale_storage_read(deps.storage)
+        .load(id.as_bytes())
+        .map_err(ContractError::Std)?;
+
+    ensure_open(&sale)?;
+
+    // ensure buyer is sender, open offers have no buyer to reject them
+    if sale.buyer.as_ref() != Some(&info.sender) {
+        return Err(ContractError::Unauthorized {
+            error: String::from("only buyer can reject sale"),
+        });
+    }
+
+    let response = close_sale(deps, &env, sale, Status::Rejected, "reject_sale")?;
+
+    Ok(response.add_attribute("reason", reason))
+}
+
+fn reclaim_expired(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response, ContractError> {
+    // validate params
+    if !info.funds.is_empty() {
+        return Err(contract_err("no funds should be sent during sale reclaim"));
+    }
+
+    // ensure existence
+    let sale = get_sale_storage_read(deps.storage)
+        .load(id.as_bytes())
+        .map_err(ContractError::Std)?;
+
+    ensure_open(&sale)?;
+
+    // ensure expired, anyone can reclaim an expired sale
+    let expired = match &sale.expires_at {
+        Some(expires_at) => expires_at.is_expired(&env.block),
+        None => false,
+    };
+    if !expired {
+        return Err(contract_err("sale has not expired"));
+    }
+
+    close_sale(deps, &env, sale, Status::Expired, "reclaim_expired")
+}
+
+// return the remaining asset to the owner and any escrowed price to the buyer
+fn close_sale(
+    deps: DepsMut,
+    env: &Env,
+    mut sale: Sale,
+    status: Status,
+    action: &str,
+) -> Result<Response, ContractError> {
+    let (asset, price) = sale_portion(&sale, sale.remaining);
+    let mut response = Response::new();
+
+    // return asset back to owner
+    response = response.add_messages(asset_transfer_msgs(
+        env,
+        sale.restricted,
+        &asset,
+        &sale.owner,
+    ));
+
+    // refund escrowed price in dcc token to buyer
+    if let (true, Some(buyer)) = (sale.funded, &sale.buyer) {
+        let state = config_read(deps.storage).load()?;
+        response = response.add_message(WasmMsg::Execute {
+            contract_addr: state.dcc_address.into_string(),
+            msg: to_binary(&DCCWasmMsg::Transfer {
+                amount: price.amount,
+                recipient: buyer.to_string(),
+            })?,
+            funds: vec![],
+        });
+    }
+
+    // update status
+    sale.status = status;
+    save_sale(deps.storage, &sale)?;
+
+    response = response.add_attributes(sale_attributes(
+        action,
+        &sale,
+        sale.buyer.as_ref(),
+        &asset,
+        &price,
+    ));
+
+    Ok(response)
+}
+
+// the asset and price of a quantity of the sale
+fn sale_portion(sale: &Sale, quantity: Uint128) -> (Vec<Coin>, Coin) {
+    let asset = sale
+        .asset
+        .iter()
+        .map(|coin| Coin {
+            denom: coin.denom.clone(),
+            amount: coin.amount / sale.quantity * quantity,
+        })
+        .collect();
+    let price = Coin {
+        denom: sale.price.denom.clone(),
+        amount: sale.price.amount / sale.quantity * quantity,
+    };
+    (asset, price)
+}
+
+// ensure the address holds the attributes required by a restricted marker
+fn ensure_required_attributes(
+    deps: Deps,
+    marker: &MarkerAccount,
+    address: &Addr,
+) -> Result<(), ContractError> {
+    let querier = AttributeQuerier::new(&deps.querier);
+    for required_attribute in marker.required_attributes.iter() {
+        let res = querier.attribute(address.to_string(), required_attribute.to_string(), None)?;
+        if res.attributes.is_empty() {
+            return Err(contract_err(&format!(
+                "buyer is missing required attribute {}",
+                required_attribute
+            )));
+        }
+    }
+    Ok(())
+}
+
+// transfer asset out of the contract
+fn asset_transfer_msgs(env: &Env, restricted: bool, asset: &[Coin], to: &Addr) -> Vec<CosmosMsg> {
+    if restricted {
+        asset
+            .iter()
+            .map(|coin| {
+                MsgTransferRequest {
+                    amount: Some(ProvCoin {
+                        denom: coin.denom.clone(),
+                        amount: coin.amount.to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                    from_address: env.contract.address.to_string(),
+                    to_address: to.to_string(),
+                }
+                .into()
+            })
+            .collect()
+    } else {
+        vec![BankMsg::Send {
+            to_address: to.to_string(),
+            amount: asset.to_vec(),
+        }
+        .into()]
+    }
+}
+
+// sale event attributes, with a denom and amount pair per basket asset
+fn sale_attributes(
+    action: &str,
+    sale: &Sale,
+    buyer: Option<&Addr>,
+    asset: &[Coin],
+    price: &Coin,
+) -> Vec<Attribute> {
+    let mut attributes = vec![attr("action", action), attr("owner", &sale.owner)];
+    if let Some(buyer) = buyer {
+        attributes.push(attr("buyer", buyer));
+    }
+    // Index the basket coins so each attribute key is emitted once.
+    for (i, coin) in asset.iter().enumerate() {
+        attributes.push(attr(format!("asset_denom_{}", i), &coin.denom));
+        attributes.push(attr(format!("asset_amount_{}", i), coin.amount));
+    }
+    attributes.extend(vec![
+        attr("price_denom", &price.denom),
+        attr("price_amount", price.amount),
+        attr("id", &sale.id),
+    ]);
+    attributes
+}
+
+// ensure the sale can still be filled
+fn ensure_open(sale: &Sale) -> Result<(), ContractError> {
+    match sale.status {
+        Status::Pending | Status::PartiallyFilled => Ok(()),
+        _ => Err(contract_err(&format!(
+            "sale is in invalid state {}",
+            sale.status,
+        ))),
+    }
+}
+
+fn ensure_not_expired(env: &Env, sale: &Sale) -> Result<(), ContractError> {
+    if let Some(expires_at) = &sale.expires_at {
+        if expires_at.is_expired(&env.block) {
+            return Err(contract_err("sale has expired"));
+        }
+    }
+    Ok(())
+}
+
+fn update_fee_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    collector: String,
+    bps: u16,
+    min_fee: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    // validate params
+    if !info.funds.is_empty() {
+        return Err(contract_err(
+            "no funds should be sent during fee config update",
+        ));
+    }
+
+    let collector = deps.api.addr_validate(&collector)?;
+    let mut state = config_read(deps.storage).load()?;
+
+    // ensure admin is sender
+    if state.admin != info.sender {
+        return Err(ContractError::Unauthorized {
+            error: String::from("only admin can update fee config"),
+        });
+    }
+
+    let mut attributes = vec![
+        attr("action", "update_fee_config"),
+        attr("collector", &collector),
+        attr("bps", bps.to_string()),
+    ];
+    if let Some(min_fee) = min_fee {
+        attributes.push(attr("min_fee", min_fee));
+    }
+
+    state.fee_config = Some(FeeConfig {
+        collector,
+        bps,
+        min_fee,
+    });
+    config(deps.storage).save(&state)?;
+
+    Ok(Response::new().add_attributes(attributes))
+}
+
+fn propose_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    expires_at: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    // validate params
+    if !info.funds.is_empty() {
+        return Err(contract_err("no funds should be sent during propose admin"));
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let mut state = config_read(deps.storage).load()?;
+
+    // ensure admin is sender
+    if state.admin != info.sender {
+        return Err(ContractError::Unauthorized {
+            error: String::from("only admin can propose admin"),
+        });
+    }
+
+    if state.admin == address {
+        return Err(contract_err("admin address is unchanged"));
+    }
+
+    // ensure expiry is in the future
+    if let Some(expires_at) = expires_at {
+        if expires_at <= Uint128::from(env.block.height) {
+            return Err(contract_err("admin proposal expiry must be in the future"));
+        }
+    }
+
+    // replace any existing proposal
+    state.pending_admin = Some(PendingAdmin {
+        address,
+        expires_at,
+    });
+    config(deps.storage).save(&state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "propose_admin"),
+        attr("admin", state.admin),
+        attr("pending_admin", id),
+    ]))
+}
+
+fn accept_admin(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    // validate params
+    if !info.funds.is_empty() {
+        return Err(contract_err("no funds should be sent during accept admin"));
+    }
+
+    let mut state = config_read(deps.storage).load()?;
+
+    // ensure existence
+    let pending_admin = match state.pending_admin {
+        Some(pending_admin) => pending_admin,
+        None => return Err(contract_err("no pending admin proposal")),
+    };
+
+    // ensure pending admin is sender
+    if pending_admin.address != info.sender {
+        return Err(ContractError::Unauthorized {
+            error: String::from("only pending admin can accept admin"),
+        });
+    }
+
+    // ensure not expired
+    if let Some(expires_at) = pending_admin.expires_at {
+        if Uint128::from(env.block.height) > expires_at {
+            return Err(contract_err("admin proposal has expired"));
+        }
+    }
+
+    state.admin = pending_admin.address;
+    state.pending_admin = None;
+    config(deps.storage).save(&state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "accept_admin"),
+        attr("admin", state.admin),
+    ]))
+}
+
+fn cancel_admin_proposal(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    // validate params
+    if !info.funds.is_empty() {
+        return Err(contract_err(
+            "no funds should be sent during cancel admin proposal",
+        ));
+    }
+
+    let mut state = config_read(deps.storage).load()?;
+
+    // ensure admin is sender
+    if state.admin != info.sender {
+        return Err(ContractError::Unauthorized {
+            error: String::from("only admin can cancel admin proposal"),
+        });
+    }
+
+    // ensure existence
+    let pending_admin = match state.pending_admin {
+        Some(pending_admin) => pending_admin,
+        None => return Err(contract_err("no pending admin proposal")),
+    };
+
+    state.pending_admin = None;
+    config(deps.storage).save(&state)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "cancel_admin_proposal"),
+        attr("pending_admin", pending_admin.address),
+    ]))
+}
+
+fn get_marker(id: String, querier: &MarkerQuerier<Empty>) -> StdResult<MarkerAccount> {
+    let response = querier.marker(id)?;
+    if let Some(marker) = response.marker {
+        return if let Ok(account) = MarkerAccount::try_from(marker) {
+            Ok(account)
+        } else {
+            Err(StdError::generic_err("unable to type-cast marker account"))
+        };
+    } else {
+        Err(StdError::generic_err("no marker found for id"))
+    }
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    msg.validate()?;
+
+    match msg {
+        QueryMsg::GetSale { id } => {
+            to_binary(&get_sale_storage_read(deps.storage).load(id.as_bytes())?)
+        }
+        QueryMsg::ListSalesByOwner {
+            owner,
+            start_after,
+            limit,
+        } => {
+            let owner = deps.api.addr_validate(&owner)?;
+            let index = sales_by_owner_read(deps.storage, &owner);
+            to_binary(&list_sales(deps, &index, start_after, limit)?)
+        }
+        QueryMsg::ListSalesByBuyer {
+            buyer,
+            start_after,
+            limit,
+        } => {
+            let buyer = deps.api.addr_validate(&buyer)?;
+            let index = sales_by_buyer_read(deps.storage, &buyer);
+            to_binary(&list_sales(deps, &index, start_after, limit)?)
+        }
+        QueryMsg::ListSales {
+            status: Some(status),
+            start_after,
+            limit,
+        } => {
+            let index = sales_by_status_read(deps.storage, &status);
+            to_binary(&list_sales(deps, &index, start_after, limit)?)
+        }
+        QueryMsg::ListSales {
+            status: None,
+            start_after,
+            limit,
+        } => {
+            let index = get_sale_storage_read(deps.storage);
+            to_binary(&list_sales(deps, &index, start_after, limit)?)
+        }
+        QueryMsg::GetContractInfo {} => to_binary(&config_read(deps.storage).load()?),
+        QueryMsg::GetVersionInfo {} => to_binary(&get_contract_version(deps.storage)?),
+    }
+}
+
+// list a page of the sales in an index keyed by sale ID
+fn list_sales<T: Serialize + DeserializeOwned>(
+    deps: Deps,
+    index: &ReadonlyBucket<T>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Sales> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    // bucket ranges are inclusive of the start key, so skip past the provided sale ID
+    let start = start_after.map(|id| {
+        let mut key = id.into_bytes();
+        key.push(0);
+        key
+    });
+
+    // read one extra sale to determine whether there is another page
+    let sale_storage = get_sale_storage_read(deps.storage);
+    let mut sales = index
+        .range(start.as_deref(), None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| {
+            let (key, _) = item?;
+            sale_storage.load(&key)
+        })
+        .collect::<StdResult<Vec<Sale>>>()?;
+
+    let next_key = if sales.len() > limit {
+        sales.truncate(limit);
+        sales.last().map(|sale| sale.id.clone())
+    } else {
+        None
+    };
+
+    Ok(Sales { sales, next_key })
+}
+
+// unit tests
+#[cfg(test)]
+mod tests {
+    use crate::contract::{execute, query};
+    use crate::msg::{DCCWasmMsg, ExecuteMsg, QueryMsg, Sales};
+    use crate::state::{
+        config, config_read, get_sale_storage_read, save_sale, Expiration, PendingAdmin, Sale,
+        State, Status,
+    };
+    use crate::ContractError;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{
+        attr, from_binary, from_slice, to_binary, Addr, BankMsg, Coin, CosmosMsg, StdError,
+        Storage, Uint128, WasmMsg,
+    };
+    use prost::Message;
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::shim::Any;
+    use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
+    use provwasm_std::types::cosmos::base::v1beta1::Coin as ProvCoin;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributeRequest, QueryAttributeResponse,
+    };
+    use provwasm_std::types::provenance::marker::v1::{
+        Access, AccessGrant, MarkerAccount, MarkerStatus, MarkerType, MsgTransferRequest,
+        QueryMarkerRequest, QueryMarkerResponse,
+    };
+
+    const ID: &str = "1bd75dc7-5c15-4ef8-9d8c-de9c106d1fdc";
+    const ADMIN_ADDRESS: &str = "tp1343puvn0uvzrmxkwzkx62z9eay56ag9y49f3af";
+    const OWNER_ADDRESS: &str = "tp1vxyyagf9p7se5rhh9vxs39tswr0srckusjq585";
+    const BUYER_ADDRESS: &str = "tp1gjuyjcgxhmv4d003ja576dmzfeu5pjjex7h5k3";
+    const ASSET_DENOM: &str = "ibc/loan1";
+    const DCC_DENOM: &str = "usdf.c";
+    const DCC_ADDRESS: &str = "tp1u9t9ung76wzw88jhr0pa5szvl6ystdfeey2mrm";
+
+    #[test]
+    fn create_sale_success() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
                 dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
             },
         );
 
+        QueryMarkerRequest::mock_response(&mut deps.querier, setup_asset_marker());
+
         let asset = Coin {
             amount: Uint128::new(1),
             denom: ASSET_DENOM.into(),
         };
 
+        let sender_info = mock_info(OWNER_ADDRESS, &[asset.clone()]);
+
         let price = Coin {
             amount: Uint128::new(100),
             denom: DCC_DENOM.into(),
         };
 
-        // try to create sale without funds
+        let create_msg = ExecuteMsg::CreateSale {
+            id: ID.into(),
+            price: price.clone(),
+            buyer: Some(BUYER_ADDRESS.into()),
+            allowed_buyers: None,
+            quantity: None,
+            expires_at: None,
+            asset: None,
+        };
+
+        let create_response = execute(deps.as_mut(), mock_env(), sender_info, create_msg);
+
+        match create_response {
+            Ok(response) => {
+                assert_eq!(response.attributes.len(), 8);
+                assert_eq!(response.attributes[0], attr("action", "create_sale"));
+                assert_eq!(response.attributes[1], attr("owner", OWNER_ADDRESS));
+                assert_eq!(response.attributes[2], attr("buyer", BUYER_ADDRESS));
+                assert_eq!(response.attributes[3], attr("asset_denom_0", &asset.denom));
+                assert_eq!(response.attributes[4], attr("asset_amount_0", asset.amount));
+                assert_eq!(response.attributes[5], attr("price_denom", &price.denom));
+                assert_eq!(response.attributes[6], attr("price_amount", price.amount));
+                assert_eq!(response.attributes[7], attr("id", ID));
+            }
+            Err(error) => {
+                panic!("failed to create asset sale: {:?}", error)
+            }
+        }
+
+        match get_sale_storage_read(&deps.storage).load(ID.as_bytes()) {
+            Ok(sale) => {
+                assert_eq!(
+                    Sale {
+                        id: ID.into(),
+                        asset: vec![asset],
+                        owner: Addr::unchecked(OWNER_ADDRESS),
+                        buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                        price,
+                        status: Status::Pending,
+                        funded: false,
+                        expires_at: None,
+                        restricted: false,
+                        allowed_buyers: vec![],
+                        quantity: Uint128::one(),
+                        remaining: Uint128::one(),
+                        version: 0,
+                    },
+                    sale
+                );
+            }
+            _ => {
+                panic!("sale not found in storage")
+            }
+        }
+    }
+
+    #[test]
+    fn create_sale_invalid_params() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let asset = Coin {
+            amount: Uint128::new(1),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
+
+        // try to create sale without funds
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price: price.clone(),
+                buyer: Some(BUYER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: None,
+                asset: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "funds should be sent during sale creation")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        let funds = vec![asset.clone(), asset.clone()];
+
+        // try to create sale with the same asset denom twice
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &funds),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price: price.clone(),
+                buyer: Some(BUYER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: None,
+                asset: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(
+                    msg,
+                    format!("asset denom {} should only be sent once", ASSET_DENOM)
+                )
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        let funds = vec![Coin {
+            amount: Uint128::new(1),
+            denom: DCC_DENOM.into(),
+        }];
+
+        // try to create sale with same buyer as owner
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &funds),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price: price.clone(),
+                buyer: Some(OWNER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: None,
+                asset: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "owner cannot also be the buyer")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // try to create sale with invalid restricted token
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &funds),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price: price.clone(),
+                buyer: Some(BUYER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: None,
+                asset: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::UnsupportedMarkerType { error } => {
+                assert_eq!(error, "fund marker type must be unrestricted")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        let funds = vec![asset];
+
+        QueryMarkerRequest::mock_response(&mut deps.querier, setup_asset_marker());
+
+        // try to create sale with wrong dcc denom price
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &funds),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price: Coin {
+                    amount: Uint128::new(1),
+                    denom: "somethingelse".into(),
+                },
+                buyer: Some(BUYER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: None,
+                asset: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, format!("price denom must be {}", DCC_DENOM))
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![Coin {
+                    amount: Uint128::new(1),
+                    denom: ASSET_DENOM.into(),
+                }],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: price.clone(),
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        // try to create sale duplicate id
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &funds),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price,
+                buyer: Some(BUYER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: None,
+                asset: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, format!("sale with id {} already exists", ID))
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn cancel_sale_success() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let asset = Coin {
+            amount: Uint128::new(1),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![asset.clone()],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: price.clone(),
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        let sender_info = mock_info(OWNER_ADDRESS, &[]);
+        let cancel_msg = ExecuteMsg::CancelSale { id: ID.into() };
+        let cancel_response = execute(deps.as_mut(), mock_env(), sender_info, cancel_msg);
+
+        match cancel_response {
+            Ok(response) => {
+                assert_eq!(response.attributes.len(), 8);
+
+                assert_eq!(response.attributes[0], attr("action", "cancel_sale"));
+                assert_eq!(response.attributes[1], attr("owner", OWNER_ADDRESS));
+                assert_eq!(response.attributes[2], attr("buyer", BUYER_ADDRESS));
+                assert_eq!(response.attributes[3], attr("asset_denom_0", &asset.denom));
+                assert_eq!(response.attributes[4], attr("asset_amount_0", asset.amount));
+                assert_eq!(response.attributes[5], attr("price_denom", &price.denom));
+                assert_eq!(response.attributes[6], attr("price_amount", price.amount));
+                assert_eq!(response.attributes[7], attr("id", ID));
+            }
+            Err(error) => {
+                panic!("failed to cancel asset sale: {:?}", error)
+            }
+        }
+
+        match get_sale_storage_read(&deps.storage).load(ID.as_bytes()) {
+            Ok(sale) => {
+                assert_eq!(
+                    Sale {
+                        id: ID.into(),
+                        asset: vec![asset],
+                        owner: Addr::unchecked(OWNER_ADDRESS),
+                        buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                        price,
+                        status: Status::Canceled,
+                        funded: false,
+                        expires_at: None,
+                        restricted: false,
+                        allowed_buyers: vec![],
+                        quantity: Uint128::one(),
+                        remaining: Uint128::one(),
+                        version: 0,
+                    },
+                    sale
+                );
+            }
+            _ => {
+                panic!("sale not found in storage")
+            }
+        }
+    }
+
+    #[test]
+    fn cancel_sale_invalid_params() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let asset = Coin {
+            amount: Uint128::new(1),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
+
+        let id2 = "91c5ce26-0ef5-4163-9786-2d8f81eb9f56";
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![asset.clone()],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: price.clone(),
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: id2.into(),
+                asset: vec![asset.clone()],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price,
+                status: Status::Canceled,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        // try to cancel sale with funds
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &vec![asset]),
+            ExecuteMsg::CancelSale { id: ID.into() },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "no funds should be sent during sale cancellation")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // try to cancel sale with not found ID
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &vec![]),
+            ExecuteMsg::CancelSale {
+                id: "b183d578-418f-4edd-b23d-9219d408178c".into(),
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::NotFound { .. }) => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // try to cancel sale in not pending state
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &vec![]),
+            ExecuteMsg::CancelSale { id: id2.into() },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale is in invalid state Canceled")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // try to cancel sale not authorized
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &vec![]),
+            ExecuteMsg::CancelSale { id: ID.into() },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only owner can cancel sale")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn reject_sale_success() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let asset = Coin {
+            amount: Uint128::new(1),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![asset.clone()],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: price.clone(),
+                status: Status::Pending,
+                funded: true,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        // try to reject sale as owner
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::RejectSale {
+                id: ID.into(),
+                reason: "wrong price".into(),
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only buyer can reject sale")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // reject funded sale as buyer returns both escrowed legs
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::RejectSale {
+                id: ID.into(),
+                reason: "wrong price".into(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: OWNER_ADDRESS.into(),
+                amount: vec![asset],
+            })
+        );
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::Transfer {
+                    amount: price.amount,
+                    recipient: BUYER_ADDRESS.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+        assert_eq!(response.attributes.len(), 9);
+        assert_eq!(response.attributes[0], attr("action", "reject_sale"));
+        assert_eq!(response.attributes[8], attr("reason", "wrong price"));
+
+        let sale = get_sale_storage_read(&deps.storage)
+            .load(ID.as_bytes())
+            .unwrap();
+        assert_eq!(sale.status, Status::Rejected);
+
+        // try to reject sale again
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::RejectSale {
+                id: ID.into(),
+                reason: "wrong price".into(),
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale is in invalid state Rejected")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn update_sale_success() {
+        let mut deps = mock_provenance_dependencies();
+        let new_buyer = "tp1q4h2ju0t3wj3k2j06rugml3xnp4ytflu4yyv9c";
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![Coin {
+                    amount: Uint128::new(1),
+                    denom: ASSET_DENOM.into(),
+                }],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: price.clone(),
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        let new_price = Coin {
+            amount: Uint128::new(150),
+            denom: DCC_DENOM.into(),
+        };
+
+        // try to update sale as buyer
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::UpdateSale {
+                id: ID.into(),
+                price: new_price.clone(),
+                buyer: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only owner can update sale")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // amend the price and buyer
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::UpdateSale {
+                id: ID.into(),
+                price: new_price.clone(),
+                buyer: Some(new_buyer.into()),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(response.attributes[0], attr("action", "update_sale"));
+        assert_eq!(response.attributes[2], attr("buyer", new_buyer));
+        assert_eq!(response.attributes[6], attr("price_amount", "150"));
+        assert_eq!(response.attributes[8], attr("version", "1"));
+
+        let sale = get_sale_storage_read(&deps.storage)
+            .load(ID.as_bytes())
+            .unwrap();
+        assert_eq!(sale.price, new_price);
+        assert_eq!(sale.buyer, Some(Addr::unchecked(new_buyer)));
+        assert_eq!(sale.version, 1);
+
+        // the previous buyer no longer lists the sale
+        let page: Sales = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListSalesByBuyer {
+                    buyer: BUYER_ADDRESS.into(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(page.sales.is_empty());
+
+        // try to complete against the previous version
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(new_buyer, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: Some(0),
+                expected_price: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale version 1 does not match expected version 0")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // try to complete against the previous price
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(new_buyer, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: Some(price),
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(
+                    msg,
+                    "sale price 150usdf.c does not match expected price 100usdf.c"
+                )
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // complete against the amended terms
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(new_buyer, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: Some(1),
+                expected_price: Some(new_price.clone()),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
+                    amount: new_price.amount,
+                    sender: new_buyer.into(),
+                    recipient: OWNER_ADDRESS.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+
+        // try to update a completed sale
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::UpdateSale {
+                id: ID.into(),
+                price: new_price,
+                buyer: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale is in invalid state Complete")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn complete_sale_success() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let asset = Coin {
+            amount: Uint128::new(1),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![asset.clone()],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: price.clone(),
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        let sender_info = mock_info(BUYER_ADDRESS, &[]);
+        let complete_msg = ExecuteMsg::CompleteSale {
+            id: ID.into(),
+            quantity: None,
+            expected_version: None,
+            expected_price: None,
+        };
+        let complete_response = execute(deps.as_mut(), mock_env(), sender_info, complete_msg);
+
+        match complete_response {
+            Ok(response) => {
+                assert_eq!(response.attributes.len(), 11);
+
+                assert_eq!(response.attributes[0], attr("action", "complete_sale"));
+                assert_eq!(response.attributes[1], attr("owner", OWNER_ADDRESS));
+                assert_eq!(response.attributes[2], attr("buyer", BUYER_ADDRESS));
+                assert_eq!(response.attributes[3], attr("asset_denom_0", &asset.denom));
+                assert_eq!(response.attributes[4], attr("asset_amount_0", asset.amount));
+                assert_eq!(response.attributes[5], attr("price_denom", &price.denom));
+                assert_eq!(response.attributes[6], attr("price_amount", price.amount));
+                assert_eq!(response.attributes[7], attr("id", ID));
+                assert_eq!(response.attributes[8], attr("quantity", "1"));
+                assert_eq!(response.attributes[9], attr("remaining", "0"));
+                assert_eq!(response.attributes[10], attr("fee_amount", "0"));
+            }
+            Err(error) => {
+                panic!("failed to complete asset sale: {:?}", error)
+            }
+        }
+
+        match get_sale_storage_read(&deps.storage).load(ID.as_bytes()) {
+            Ok(sale) => {
+                assert_eq!(
+                    Sale {
+                        id: ID.into(),
+                        asset: vec![asset],
+                        owner: Addr::unchecked(OWNER_ADDRESS),
+                        buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                        price,
+                        status: Status::Complete,
+                        funded: false,
+                        expires_at: None,
+                        restricted: false,
+                        allowed_buyers: vec![],
+                        quantity: Uint128::one(),
+                        remaining: Uint128::zero(),
+                        version: 0,
+                    },
+                    sale
+                );
+            }
+            _ => {
+                panic!("sale not found in storage")
+            }
+        }
+    }
+
+    #[test]
+    fn complete_sale_invalid_params() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let asset = Coin {
+            amount: Uint128::new(1),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
+
+        let id2 = "91c5ce26-0ef5-4163-9786-2d8f81eb9f56";
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![asset.clone()],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: price.clone(),
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: id2.into(),
+                asset: vec![asset.clone()],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price,
+                status: Status::Canceled,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
+            },
+        );
+
+        // try to complete sale with funds
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &vec![asset]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "no funds should be sent during sale completion")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // try to complete sale with not found ID
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &vec![]),
+            ExecuteMsg::CompleteSale {
+                id: "b183d578-418f-4edd-b23d-9219d408178c".into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::NotFound { .. }) => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // try to complete sale in not pending state
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &vec![]),
+            ExecuteMsg::CompleteSale {
+                id: id2.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale is in invalid state Canceled")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // try to complete sale not authorized
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &vec![]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only buyer can complete sale")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn complete_sale_with_fee() {
+        let mut deps = mock_provenance_dependencies();
+        let collector = "tp1q4h2ju0t3wj3k2j06rugml3xnp4ytflu4yyv9c";
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        // try to update fee config as non admin
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::UpdateFeeConfig {
+                collector: collector.into(),
+                bps: 250,
+                min_fee: Some(Uint128::new(5)),
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only admin can update fee config")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // charge 2.5% with a minimum of 5
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::UpdateFeeConfig {
+                collector: collector.into(),
+                bps: 250,
+                min_fee: Some(Uint128::new(5)),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(response.attributes.len(), 4);
+        assert_eq!(response.attributes[0], attr("action", "update_fee_config"));
+        assert_eq!(response.attributes[1], attr("collector", collector));
+        assert_eq!(response.attributes[2], attr("bps", "250"));
+        assert_eq!(response.attributes[3], attr("min_fee", "5"));
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![Coin {
+                    amount: Uint128::new(10),
+                    denom: ASSET_DENOM.into(),
+                }],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: Coin {
+                    amount: Uint128::new(1000),
+                    denom: DCC_DENOM.into(),
+                },
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::new(10),
+                remaining: Uint128::new(10),
+                version: 0,
+            },
+        );
+
+        // the percentage fee applies above the minimum
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: Some(Uint128::new(8)),
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 3);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
+                    amount: Uint128::new(780),
+                    sender: BUYER_ADDRESS.into(),
+                    recipient: OWNER_ADDRESS.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
+                    amount: Uint128::new(20),
+                    sender: BUYER_ADDRESS.into(),
+                    recipient: collector.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+        assert_eq!(response.attributes[10], attr("fee_amount", "20"));
+
+        // the minimum fee applies to small fills
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: Some(Uint128::new(1)),
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
+                    amount: Uint128::new(5),
+                    sender: BUYER_ADDRESS.into(),
+                    recipient: collector.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+        assert_eq!(response.attributes[10], attr("fee_amount", "5"));
+    }
+
+    #[test]
+    fn complete_sale_fee_takes_price() {
+        let mut deps = mock_provenance_dependencies();
+        let collector = "tp1q4h2ju0t3wj3k2j06rugml3xnp4ytflu4yyv9c";
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        // charge the whole price
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::UpdateFeeConfig {
+                collector: collector.into(),
+                bps: 10_000,
+                min_fee: None,
+            },
+        )
+        .unwrap();
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![Coin {
+                    amount: Uint128::new(10),
+                    denom: ASSET_DENOM.into(),
+                }],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
+                price: Coin {
+                    amount: Uint128::new(1000),
+                    denom: DCC_DENOM.into(),
+                },
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::new(10),
+                remaining: Uint128::new(10),
+                version: 0,
+            },
+        );
+
+        // the seller payment is skipped, only the fee and asset are transferred
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: Some(Uint128::new(5)),
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
+                    amount: Uint128::new(500),
+                    sender: BUYER_ADDRESS.into(),
+                    recipient: collector.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+
+        // a minimum fee at the lot price also takes the whole price
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::UpdateFeeConfig {
+                collector: collector.into(),
+                bps: 0,
+                min_fee: Some(Uint128::new(1000)),
+            },
+        )
+        .unwrap();
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
+                    amount: Uint128::new(500),
+                    sender: BUYER_ADDRESS.into(),
+                    recipient: collector.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+        assert!(response.attributes.contains(&attr("fee_amount", "500")));
+    }
+
+    #[test]
+    fn basket_sale_success() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        QueryMarkerRequest::mock_response(&mut deps.querier, setup_asset_marker());
+
+        let basket = vec![
+            Coin {
+                amount: Uint128::new(1),
+                denom: ASSET_DENOM.into(),
+            },
+            Coin {
+                amount: Uint128::new(2),
+                denom: "ibc/loan2".into(),
+            },
+        ];
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
+
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &basket),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price,
+                buyer: Some(BUYER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: None,
+                asset: None,
+            },
+        )
+        .unwrap();
+
+        // verify the basket is enumerated
+        assert_eq!(response.attributes.len(), 10);
+        assert_eq!(response.attributes[3], attr("asset_denom_0", ASSET_DENOM));
+        assert_eq!(response.attributes[4], attr("asset_amount_0", "1"));
+        assert_eq!(response.attributes[5], attr("asset_denom_1", "ibc/loan2"));
+        assert_eq!(response.attributes[6], attr("asset_amount_1", "2"));
+
+        // complete sale delivers the whole basket at once
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: BUYER_ADDRESS.into(),
+                amount: basket,
+            })
+        );
+    }
+
+    #[test]
+    fn single_asset_sale_compatibility() {
+        // sales stored before baskets hold a single coin
+        let sale: Sale = from_slice(
+            format!(
+                r#"{{"id":"{}","asset":{{"denom":"{}","amount":"1"}},"owner":"{}","buyer":"{}","price":{{"denom":"{}","amount":"100"}},"status":"pending"}}"#,
+                ID, ASSET_DENOM, OWNER_ADDRESS, BUYER_ADDRESS, DCC_DENOM
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            sale.asset,
+            vec![Coin {
+                amount: Uint128::new(1),
+                denom: ASSET_DENOM.into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn open_offer_partial_fill() {
+        let mut deps = mock_provenance_dependencies();
+        let other_buyer = "tp1q4h2ju0t3wj3k2j06rugml3xnp4ytflu4yyv9c";
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        QueryMarkerRequest::mock_response(&mut deps.querier, setup_asset_marker());
+
+        let asset = Coin {
+            amount: Uint128::new(10),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(1000),
+            denom: DCC_DENOM.into(),
+        };
+
+        // create an open offer of 10 lots restricted to two buyers
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[asset.clone()]),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price,
+                buyer: None,
+                allowed_buyers: Some(vec![BUYER_ADDRESS.into(), other_buyer.into()]),
+                quantity: Some(Uint128::new(10)),
+                expires_at: None,
+                asset: None,
+            },
+        )
+        .unwrap();
+
+        // only allowed buyers can fill
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: Some(Uint128::new(1)),
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only allowed buyers can complete sale")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // fill part of the offer
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: Some(Uint128::new(4)),
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
+                    amount: Uint128::new(400),
+                    sender: BUYER_ADDRESS.into(),
+                    recipient: OWNER_ADDRESS.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: BUYER_ADDRESS.into(),
+                amount: vec![Coin {
+                    amount: Uint128::new(4),
+                    denom: ASSET_DENOM.into(),
+                }],
+            })
+        );
+
+        let sale = get_sale_storage_read(&deps.storage)
+            .load(ID.as_bytes())
+            .unwrap();
+        assert_eq!(sale.status, Status::PartiallyFilled);
+        assert_eq!(sale.remaining, Uint128::new(6));
+
+        // try to fill more than remains
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(other_buyer, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: Some(Uint128::new(7)),
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "quantity exceeds remaining quantity 6")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // cancel returns the unfilled portion to the owner
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::CancelSale { id: ID.into() },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: OWNER_ADDRESS.into(),
+                amount: vec![Coin {
+                    amount: Uint128::new(6),
+                    denom: ASSET_DENOM.into(),
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn open_offer_fill_remaining() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
+                id: ID.into(),
+                asset: vec![Coin {
+                    amount: Uint128::new(10),
+                    denom: ASSET_DENOM.into(),
+                }],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: None,
+                price: Coin {
+                    amount: Uint128::new(1000),
+                    denom: DCC_DENOM.into(),
+                },
+                status: Status::PartiallyFilled,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::new(10),
+                remaining: Uint128::new(6),
+                version: 0,
+            },
+        );
+
+        // the owner cannot fill its own offer
         let err = execute(
             deps.as_mut(),
             mock_env(),
             mock_info(OWNER_ADDRESS, &[]),
-            ExecuteMsg::CreateSale {
+            ExecuteMsg::CompleteSale {
                 id: ID.into(),
-                price: price.clone(),
-                buyer: BUYER_ADDRESS.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
             },
         )
         .unwrap_err();
 
         match err {
             ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "funds should be sent during sale creation")
+                assert_eq!(msg, "owner cannot also be the buyer")
             }
             _ => panic!("unexpected execute error"),
         }
 
-        let funds = vec![asset.clone(), price.clone()];
-
-        // try to create sale with multiple funds
-        let err = execute(
+        // any buyer fills the remaining quantity by default
+        let response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &funds),
-            ExecuteMsg::CreateSale {
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
                 id: ID.into(),
-                price: price.clone(),
-                buyer: BUYER_ADDRESS.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
             },
         )
-        .unwrap_err();
+        .unwrap();
 
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "only one fund should be sent during sale creation")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        assert_eq!(response.attributes[8], attr("quantity", "6"));
+        assert_eq!(response.attributes[9], attr("remaining", "0"));
 
-        let funds = vec![Coin {
+        let sale = get_sale_storage_read(&deps.storage)
+            .load(ID.as_bytes())
+            .unwrap();
+        assert_eq!(sale.status, Status::Complete);
+        assert!(sale.remaining.is_zero());
+    }
+
+    #[test]
+    fn fund_sale_success() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let asset = Coin {
             amount: Uint128::new(1),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(100),
             denom: DCC_DENOM.into(),
-        }];
+        };
 
-        // try to create sale with same buyer as owner
-        let err = execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info(OWNER_ADDRESS, &funds),
-            ExecuteMsg::CreateSale {
+        setup_sale_base(
+            &mut deps.storage,
+            &Sale {
                 id: ID.into(),
+                asset: vec![asset.clone()],
+                owner: Addr::unchecked(OWNER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
                 price: price.clone(),
-                buyer: OWNER_ADDRESS.into(),
+                status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
             },
-        )
-        .unwrap_err();
+        );
 
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "owner cannot also be the buyer")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        // escrow the price as buyer
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::FundSale { id: ID.into() },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::ExecutorTransfer {
+                    amount: price.amount,
+                    sender: BUYER_ADDRESS.into(),
+                    recipient: mock_env().contract.address.into_string(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+        assert_eq!(response.attributes[0], attr("action", "fund_sale"));
 
-        // try to create sale with invalid restricted token
+        // try to fund sale again
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &funds),
-            ExecuteMsg::CreateSale {
-                id: ID.into(),
-                price: price.clone(),
-                buyer: BUYER_ADDRESS.into(),
-            },
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::FundSale { id: ID.into() },
         )
         .unwrap_err();
 
         match err {
-            ContractError::UnsupportedMarkerType { error } => {
-                assert_eq!(error, "fund marker type must be unrestricted")
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale is already funded")
             }
             _ => panic!("unexpected execute error"),
         }
 
-        let funds = vec![asset];
-
-        QueryMarkerRequest::mock_response(&mut deps.querier, setup_asset_marker());
-
-        // try to create sale with wrong dcc denom price
-        let err = execute(
+        // complete funded sale as owner swaps both escrowed legs
+        let response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &funds),
-            ExecuteMsg::CreateSale {
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
                 id: ID.into(),
-                price: Coin {
-                    amount: Uint128::new(1),
-                    denom: "somethingelse".into(),
-                },
-                buyer: BUYER_ADDRESS.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
             },
         )
-        .unwrap_err();
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::Transfer {
+                    amount: price.amount,
+                    recipient: OWNER_ADDRESS.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: BUYER_ADDRESS.into(),
+                amount: vec![asset],
+            })
+        );
 
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, format!("price denom must be {}", DCC_DENOM))
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        let sale = get_sale_storage_read(&deps.storage)
+            .load(ID.as_bytes())
+            .unwrap();
+        assert_eq!(sale.status, Status::Complete);
+        assert!(sale.funded);
+    }
+
+    #[test]
+    fn fund_sale_refund_on_cancel() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
+            &mut deps.storage,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
+            },
+        );
+
+        let asset = Coin {
+            amount: Uint128::new(1),
+            denom: ASSET_DENOM.into(),
+        };
+
+        let price = Coin {
+            amount: Uint128::new(100),
+            denom: DCC_DENOM.into(),
+        };
 
         setup_sale_base(
             &mut deps.storage,
             &Sale {
                 id: ID.into(),
-                asset: Coin {
-                    amount: Uint128::new(1),
-                    denom: ASSET_DENOM.into(),
-                },
+                asset: vec![asset.clone()],
                 owner: Addr::unchecked(OWNER_ADDRESS),
-                buyer: Addr::unchecked(BUYER_ADDRESS),
+                buyer: Some(Addr::unchecked(BUYER_ADDRESS)),
                 price: price.clone(),
                 status: Status::Pending,
+                funded: false,
+                expires_at: None,
+                restricted: false,
+                allowed_buyers: vec![],
+                quantity: Uint128::one(),
+                remaining: Uint128::one(),
+                version: 0,
             },
         );
 
-        // try to create sale duplicate id
+        // try to fund sale as owner
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &funds),
-            ExecuteMsg::CreateSale {
-                id: ID.into(),
-                price,
-                buyer: BUYER_ADDRESS.into(),
-            },
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::FundSale { id: ID.into() },
         )
         .unwrap_err();
 
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, format!("sale with id {} already exists", ID))
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only buyer can fund sale")
             }
             _ => panic!("unexpected execute error"),
         }
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::FundSale { id: ID.into() },
+        )
+        .unwrap();
+
+        // cancel funded sale as buyer returns both escrowed legs
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CancelSale { id: ID.into() },
+        )
+        .unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: OWNER_ADDRESS.into(),
+                amount: vec![asset],
+            })
+        );
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: DCC_ADDRESS.into(),
+                msg: to_binary(&DCCWasmMsg::Transfer {
+                    amount: price.amount,
+                    recipient: BUYER_ADDRESS.into(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+
+        let sale = get_sale_storage_read(&deps.storage)
+            .load(ID.as_bytes())
+            .unwrap();
+        assert_eq!(sale.status, Status::Canceled);
     }
 
     #[test]
-    fn cancel_sale_success() {
+    fn sale_expiration() {
         let mut deps = mock_provenance_dependencies();
 
         setup_test_base(
@@ -539,9 +3078,13 @@ mod tests {
                 admin: Addr::unchecked(ADMIN_ADDRESS),
                 dcc_address: Addr::unchecked(DCC_ADDRESS),
                 dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
             },
         );
 
+        QueryMarkerRequest::mock_response(&mut deps.querier, setup_asset_marker());
+
         let asset = Coin {
             amount: Uint128::new(1),
             denom: ASSET_DENOM.into(),
@@ -552,62 +3095,114 @@ mod tests {
             denom: DCC_DENOM.into(),
         };
 
-        setup_sale_base(
-            &mut deps.storage,
-            &Sale {
+        // try to create sale that has already expired
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[asset.clone()]),
+            ExecuteMsg::CreateSale {
                 id: ID.into(),
-                asset: asset.clone(),
-                owner: Addr::unchecked(OWNER_ADDRESS),
-                buyer: Addr::unchecked(BUYER_ADDRESS),
                 price: price.clone(),
-                status: Status::Pending,
+                buyer: Some(BUYER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: Some(Expiration::AtTime(mock_env().block.time)),
+                asset: None,
             },
-        );
+        )
+        .unwrap_err();
 
-        let sender_info = mock_info(OWNER_ADDRESS, &[]);
-        let cancel_msg = ExecuteMsg::CancelSale { id: ID.into() };
-        let cancel_response = execute(deps.as_mut(), mock_env(), sender_info, cancel_msg);
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale expiry must be in the future")
+            }
+            _ => panic!("unexpected execute error"),
+        }
 
-        match cancel_response {
-            Ok(response) => {
-                assert_eq!(response.attributes.len(), 8);
+        let expires_at = Uint128::from(mock_env().block.height + 10);
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[asset.clone()]),
+            ExecuteMsg::CreateSale {
+                id: ID.into(),
+                price,
+                buyer: Some(BUYER_ADDRESS.into()),
+                allowed_buyers: None,
+                quantity: None,
+                expires_at: Some(Expiration::AtHeight(expires_at)),
+                asset: None,
+            },
+        )
+        .unwrap();
 
-                assert_eq!(response.attributes[0], attr("action", "cancel_sale"));
-                assert_eq!(response.attributes[1], attr("owner", OWNER_ADDRESS));
-                assert_eq!(response.attributes[2], attr("buyer", BUYER_ADDRESS));
-                assert_eq!(response.attributes[3], attr("asset_denom", &asset.denom));
-                assert_eq!(response.attributes[4], attr("asset_amount", asset.amount));
-                assert_eq!(response.attributes[5], attr("price_denom", &price.denom));
-                assert_eq!(response.attributes[6], attr("price_amount", price.amount));
-                assert_eq!(response.attributes[7], attr("id", ID));
-            }
-            Err(error) => {
-                panic!("failed to cancel asset sale: {:?}", error)
+        // try to reclaim sale before expiry
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::ReclaimExpired { id: ID.into() },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale has not expired")
             }
+            _ => panic!("unexpected execute error"),
         }
 
-        match get_sale_storage_read(&deps.storage).load(ID.as_bytes()) {
-            Ok(sale) => {
-                assert_eq!(
-                    Sale {
-                        id: ID.into(),
-                        asset,
-                        owner: Addr::unchecked(OWNER_ADDRESS),
-                        buyer: Addr::unchecked(BUYER_ADDRESS),
-                        price,
-                        status: Status::Canceled,
-                    },
-                    sale
-                );
-            }
-            _ => {
-                panic!("sale not found in storage")
+        let mut expired_env = mock_env();
+        expired_env.block.height = expires_at.u128() as u64;
+
+        // try to complete sale after expiry
+        let err = execute(
+            deps.as_mut(),
+            expired_env.clone(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap_err();
+
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sale has expired")
             }
+            _ => panic!("unexpected execute error"),
         }
+
+        // reclaim expired sale as any address
+        let response = execute(
+            deps.as_mut(),
+            expired_env,
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::ReclaimExpired { id: ID.into() },
+        )
+        .unwrap();
+
+        assert_eq!(response.attributes[0], attr("action", "reclaim_expired"));
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: OWNER_ADDRESS.into(),
+                amount: vec![asset],
+            })
+        );
+
+        let sale = get_sale_storage_read(&deps.storage)
+            .load(ID.as_bytes())
+            .unwrap();
+        assert_eq!(sale.status, Status::Expired);
     }
 
     #[test]
-    fn cancel_sale_invalid_params() {
+    fn restricted_asset_sale() {
         let mut deps = mock_provenance_dependencies();
 
         setup_test_base(
@@ -616,9 +3211,13 @@ mod tests {
                 admin: Addr::unchecked(ADMIN_ADDRESS),
                 dcc_address: Addr::unchecked(DCC_ADDRESS),
                 dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
             },
         );
 
+        let contract_address = mock_env().contract.address;
+
         let asset = Coin {
             amount: Uint128::new(1),
             denom: ASSET_DENOM.into(),
@@ -629,100 +3228,145 @@ mod tests {
             denom: DCC_DENOM.into(),
         };
 
-        let id2 = "91c5ce26-0ef5-4163-9786-2d8f81eb9f56";
-
-        setup_sale_base(
-            &mut deps.storage,
-            &Sale {
-                id: ID.into(),
-                asset: asset.clone(),
-                owner: Addr::unchecked(OWNER_ADDRESS),
-                buyer: Addr::unchecked(BUYER_ADDRESS),
-                price: price.clone(),
-                status: Status::Pending,
-            },
-        );
-
-        setup_sale_base(
-            &mut deps.storage,
-            &Sale {
-                id: id2.into(),
-                asset: asset.clone(),
-                owner: Addr::unchecked(OWNER_ADDRESS),
-                buyer: Addr::unchecked(BUYER_ADDRESS),
-                price,
-                status: Status::Canceled,
-            },
+        let create_msg = ExecuteMsg::CreateSale {
+            id: ID.into(),
+            price: price.clone(),
+            buyer: Some(BUYER_ADDRESS.into()),
+            allowed_buyers: None,
+            quantity: None,
+            expires_at: None,
+            asset: Some(vec![asset.clone()]),
+        };
+
+        // try to create sale without transfer access on the asset marker
+        QueryMarkerRequest::mock_response(
+            &mut deps.querier,
+            setup_restricted_asset_marker(ADMIN_ADDRESS),
         );
 
-        // try to cancel sale with funds
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &vec![asset]),
-            ExecuteMsg::CancelSale { id: ID.into() },
+            mock_info(OWNER_ADDRESS, &[]),
+            create_msg.clone(),
         )
         .unwrap_err();
 
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during sale cancellation")
+            ContractError::UnsupportedMarkerType { error } => {
+                assert_eq!(error, "contract must have transfer access on asset marker")
             }
             _ => panic!("unexpected execute error"),
         }
 
-        // try to cancel sale with not found ID
+        // try to create sale for a buyer without the required attribute
+        QueryMarkerRequest::mock_response(
+            &mut deps.querier,
+            setup_restricted_asset_marker(contract_address.as_str()),
+        );
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: BUYER_ADDRESS.into(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &vec![]),
-            ExecuteMsg::CancelSale {
-                id: "b183d578-418f-4edd-b23d-9219d408178c".into(),
-            },
+            mock_info(OWNER_ADDRESS, &[]),
+            create_msg.clone(),
         )
         .unwrap_err();
 
         match err {
-            ContractError::Std(StdError::NotFound { .. }) => {}
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "buyer is missing required attribute loan.kyc.pb")
+            }
             _ => panic!("unexpected execute error"),
         }
 
-        // try to cancel sale in not pending state
-        let err = execute(
+        // create sale takes custody of the restricted asset
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: BUYER_ADDRESS.into(),
+                attributes: vec![Attribute {
+                    name: "loan.kyc.pb".into(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: BUYER_ADDRESS.into(),
+                }],
+                pagination: None,
+            },
+        );
+
+        let response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &vec![]),
-            ExecuteMsg::CancelSale { id: id2.into() },
+            mock_info(OWNER_ADDRESS, &[]),
+            create_msg,
         )
-        .unwrap_err();
+        .unwrap();
 
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "sale is in invalid state Canceled")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(
+            response.messages[0].msg,
+            CosmosMsg::from(MsgTransferRequest {
+                amount: Some(ProvCoin {
+                    denom: ASSET_DENOM.into(),
+                    amount: "1".into(),
+                }),
+                administrator: contract_address.to_string(),
+                from_address: OWNER_ADDRESS.into(),
+                to_address: contract_address.to_string(),
+            })
+        );
 
-        // try to cancel sale not authorized
-        let err = execute(
+        let sale = get_sale_storage_read(&deps.storage)
+            .load(ID.as_bytes())
+            .unwrap();
+        assert!(sale.restricted);
+
+        // complete sale transfers the restricted asset to the buyer
+        let response = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(BUYER_ADDRESS, &vec![]),
-            ExecuteMsg::CancelSale { id: ID.into() },
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ID.into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
         )
-        .unwrap_err();
+        .unwrap();
 
-        match err {
-            ContractError::Unauthorized { error } => {
-                assert_eq!(error, "only owner can cancel sale")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::from(MsgTransferRequest {
+                amount: Some(ProvCoin {
+                    denom: ASSET_DENOM.into(),
+                    amount: "1".into(),
+                }),
+                administrator: contract_address.to_string(),
+                from_address: contract_address.to_string(),
+                to_address: BUYER_ADDRESS.into(),
+            })
+        );
     }
 
     #[test]
-    fn complete_sale_success() {
+    fn list_sales_paginated() {
         let mut deps = mock_provenance_dependencies();
+        let ids = [
+            "0c2b6f0e-5d0a-4b8c-9a43-1d5f0f3b7e21",
+            "1bd75dc7-5c15-4ef8-9d8c-de9c106d1fdc",
+            "5d2a6a37-f1b8-4b61-a1d1-1fbd4c0c1a11",
+        ];
 
         setup_test_base(
             &mut deps.storage,
@@ -730,75 +3374,234 @@ mod tests {
                 admin: Addr::unchecked(ADMIN_ADDRESS),
                 dcc_address: Addr::unchecked(DCC_ADDRESS),
                 dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
             },
         );
 
-        let asset = Coin {
-            amount: Uint128::new(1),
-            denom: ASSET_DENOM.into(),
-        };
+        QueryMarkerRequest::mock_response(&mut deps.querier, setup_asset_marker());
 
-        let price = Coin {
-            amount: Uint128::new(100),
-            denom: DCC_DENOM.into(),
+        for id in ids.iter() {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(
+                    OWNER_ADDRESS,
+                    &[Coin {
+                        amount: Uint128::new(1),
+                        denom: ASSET_DENOM.into(),
+                    }],
+                ),
+                ExecuteMsg::CreateSale {
+                    id: id.to_string(),
+                    price: Coin {
+                        amount: Uint128::new(100),
+                        denom: DCC_DENOM.into(),
+                    },
+                    buyer: Some(BUYER_ADDRESS.into()),
+                    allowed_buyers: None,
+                    quantity: None,
+                    expires_at: None,
+                    asset: None,
+                },
+            )
+            .unwrap();
+        }
+
+        // list the first page of the owner's sales
+        let page: Sales = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListSalesByOwner {
+                    owner: OWNER_ADDRESS.into(),
+                    start_after: None,
+                    limit: Some(2),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(page.sales.len(), 2);
+        assert_eq!(page.sales[0].id, ids[0]);
+        assert_eq!(page.sales[1].id, ids[1]);
+        assert_eq!(page.next_key, Some(ids[1].into()));
+
+        // list the next page
+        let page: Sales = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListSalesByOwner {
+                    owner: OWNER_ADDRESS.into(),
+                    start_after: page.next_key,
+                    limit: Some(2),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(page.sales.len(), 1);
+        assert_eq!(page.sales[0].id, ids[2]);
+        assert_eq!(page.next_key, None);
+
+        // complete and cancel a sale
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::CompleteSale {
+                id: ids[0].into(),
+                quantity: None,
+                expected_version: None,
+                expected_price: None,
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::CancelSale { id: ids[1].into() },
+        )
+        .unwrap();
+
+        // verify the status indexes follow the sales
+        let list_by_status = |status: Status| -> Vec<String> {
+            let page: Sales = from_binary(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::ListSales {
+                        status: Some(status),
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            page.sales.into_iter().map(|sale| sale.id).collect()
         };
 
-        setup_sale_base(
+        assert_eq!(list_by_status(Status::Pending), vec![ids[2]]);
+        assert_eq!(list_by_status(Status::Complete), vec![ids[0]]);
+        assert_eq!(list_by_status(Status::Canceled), vec![ids[1]]);
+
+        // the buyer index keeps closed sales
+        let page: Sales = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListSalesByBuyer {
+                    buyer: BUYER_ADDRESS.into(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(page.sales.len(), 3);
+        assert_eq!(page.sales[0].status, Status::Complete);
+
+        // all sales regardless of status
+        let page: Sales = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListSales {
+                    status: None,
+                    start_after: Some(ids[0].into()),
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(page.sales.len(), 2);
+        assert_eq!(page.next_key, None);
+    }
+
+    #[test]
+    fn propose_accept_admin_success() {
+        let mut deps = mock_provenance_dependencies();
+
+        setup_test_base(
             &mut deps.storage,
-            &Sale {
-                id: ID.into(),
-                asset: asset.clone(),
-                owner: Addr::unchecked(OWNER_ADDRESS),
-                buyer: Addr::unchecked(BUYER_ADDRESS),
-                price: price.clone(),
-                status: Status::Pending,
+            &State {
+                admin: Addr::unchecked(ADMIN_ADDRESS),
+                dcc_address: Addr::unchecked(DCC_ADDRESS),
+                dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
             },
         );
 
-        let sender_info = mock_info(BUYER_ADDRESS, &[]);
-        let complete_msg = ExecuteMsg::CompleteSale { id: ID.into() };
-        let complete_response = execute(deps.as_mut(), mock_env(), sender_info, complete_msg);
+        let propose_msg = ExecuteMsg::ProposeAdmin {
+            id: OWNER_ADDRESS.into(),
+            expires_at: Some(Uint128::new(12400)),
+        };
+        let propose_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            propose_msg,
+        );
 
-        match complete_response {
+        match propose_response {
             Ok(response) => {
-                assert_eq!(response.attributes.len(), 8);
-
-                assert_eq!(response.attributes[0], attr("action", "complete_sale"));
-                assert_eq!(response.attributes[1], attr("owner", OWNER_ADDRESS));
-                assert_eq!(response.attributes[2], attr("buyer", BUYER_ADDRESS));
-                assert_eq!(response.attributes[3], attr("asset_denom", &asset.denom));
-                assert_eq!(response.attributes[4], attr("asset_amount", asset.amount));
-                assert_eq!(response.attributes[5], attr("price_denom", &price.denom));
-                assert_eq!(response.attributes[6], attr("price_amount", price.amount));
-                assert_eq!(response.attributes[7], attr("id", ID));
+                assert_eq!(response.attributes.len(), 3);
+                assert_eq!(response.attributes[0], attr("action", "propose_admin"));
+                assert_eq!(response.attributes[1], attr("admin", ADMIN_ADDRESS));
+                assert_eq!(response.attributes[2], attr("pending_admin", OWNER_ADDRESS));
             }
             Err(error) => {
-                panic!("failed to complete asset sale: {:?}", error)
+                panic!("failed to propose admin: {:?}", error)
             }
         }
 
-        match get_sale_storage_read(&deps.storage).load(ID.as_bytes()) {
-            Ok(sale) => {
-                assert_eq!(
-                    Sale {
-                        id: ID.into(),
-                        asset,
-                        owner: Addr::unchecked(OWNER_ADDRESS),
-                        buyer: Addr::unchecked(BUYER_ADDRESS),
-                        price,
-                        status: Status::Complete,
-                    },
-                    sale
-                );
+        // admin is unchanged until accepted
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.admin, Addr::unchecked(ADMIN_ADDRESS));
+        assert_eq!(
+            state.pending_admin,
+            Some(PendingAdmin {
+                address: Addr::unchecked(OWNER_ADDRESS),
+                expires_at: Some(Uint128::new(12400)),
+            })
+        );
+
+        let accept_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::AcceptAdmin {},
+        );
+
+        match accept_response {
+            Ok(response) => {
+                assert_eq!(response.attributes.len(), 2);
+                assert_eq!(response.attributes[0], attr("action", "accept_admin"));
+                assert_eq!(response.attributes[1], attr("admin", OWNER_ADDRESS));
             }
-            _ => {
-                panic!("sale not found in storage")
+            Err(error) => {
+                panic!("failed to accept admin: {:?}", error)
             }
         }
+
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.admin, Addr::unchecked(OWNER_ADDRESS));
+        assert_eq!(state.pending_admin, None);
     }
 
     #[test]
-    fn complete_sale_invalid_params() {
+    fn admin_proposal_invalid_params() {
         let mut deps = mock_provenance_dependencies();
 
         setup_test_base(
@@ -807,108 +3610,137 @@ mod tests {
                 admin: Addr::unchecked(ADMIN_ADDRESS),
                 dcc_address: Addr::unchecked(DCC_ADDRESS),
                 dcc_denom: DCC_DENOM.into(),
+                pending_admin: None,
+                fee_config: None,
             },
         );
 
-        let asset = Coin {
-            amount: Uint128::new(1),
-            denom: ASSET_DENOM.into(),
-        };
-
-        let price = Coin {
-            amount: Uint128::new(100),
-            denom: DCC_DENOM.into(),
-        };
+        // only admin can propose
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::ProposeAdmin {
+                id: OWNER_ADDRESS.into(),
+                expires_at: None,
+            },
+        )
+        .unwrap_err();
 
-        let id2 = "91c5ce26-0ef5-4163-9786-2d8f81eb9f56";
+        match err {
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only admin can propose admin")
+            }
+            _ => panic!("unexpected execute error"),
+        }
 
-        setup_sale_base(
-            &mut deps.storage,
-            &Sale {
-                id: ID.into(),
-                asset: asset.clone(),
-                owner: Addr::unchecked(OWNER_ADDRESS),
-                buyer: Addr::unchecked(BUYER_ADDRESS),
-                price: price.clone(),
-                status: Status::Pending,
+        // expiry must be in the future
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::ProposeAdmin {
+                id: OWNER_ADDRESS.into(),
+                expires_at: Some(Uint128::new(12345)),
             },
-        );
+        )
+        .unwrap_err();
 
-        setup_sale_base(
-            &mut deps.storage,
-            &Sale {
-                id: id2.into(),
-                asset: asset.clone(),
-                owner: Addr::unchecked(OWNER_ADDRESS),
-                buyer: Addr::unchecked(BUYER_ADDRESS),
-                price,
-                status: Status::Canceled,
-            },
-        );
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "admin proposal expiry must be in the future")
+            }
+            _ => panic!("unexpected execute error"),
+        }
 
-        // try to complete sale with funds
+        // nothing to accept
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &vec![asset]),
-            ExecuteMsg::CompleteSale { id: ID.into() },
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::AcceptAdmin {},
         )
         .unwrap_err();
 
         match err {
             ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during sale completion")
+                assert_eq!(msg, "no pending admin proposal")
             }
             _ => panic!("unexpected execute error"),
         }
 
-        // try to complete sale with not found ID
-        let err = execute(
+        execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &vec![]),
-            ExecuteMsg::CompleteSale {
-                id: "b183d578-418f-4edd-b23d-9219d408178c".into(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::ProposeAdmin {
+                id: OWNER_ADDRESS.into(),
+                expires_at: Some(Uint128::new(12400)),
             },
         )
+        .unwrap();
+
+        // only pending admin can accept
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BUYER_ADDRESS, &[]),
+            ExecuteMsg::AcceptAdmin {},
+        )
         .unwrap_err();
 
         match err {
-            ContractError::Std(StdError::NotFound { .. }) => {}
+            ContractError::Unauthorized { error } => {
+                assert_eq!(error, "only pending admin can accept admin")
+            }
             _ => panic!("unexpected execute error"),
         }
 
-        // try to complete sale in not pending state
+        // proposal expired
+        let mut env = mock_env();
+        env.block.height = 12401;
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info(OWNER_ADDRESS, &vec![]),
-            ExecuteMsg::CompleteSale { id: id2.into() },
+            env,
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::AcceptAdmin {},
         )
         .unwrap_err();
 
         match err {
             ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "sale is in invalid state Canceled")
+                assert_eq!(msg, "admin proposal has expired")
             }
             _ => panic!("unexpected execute error"),
         }
 
-        // try to complete sale not authorized
+        // only admin can cancel
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info(OWNER_ADDRESS, &vec![]),
-            ExecuteMsg::CompleteSale { id: ID.into() },
+            mock_info(OWNER_ADDRESS, &[]),
+            ExecuteMsg::CancelAdminProposal {},
         )
         .unwrap_err();
 
         match err {
             ContractError::Unauthorized { error } => {
-                assert_eq!(error, "only buyer can complete sale")
+                assert_eq!(error, "only admin can cancel admin proposal")
             }
             _ => panic!("unexpected execute error"),
         }
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN_ADDRESS, &[]),
+            ExecuteMsg::CancelAdminProposal {},
+        )
+        .unwrap();
+
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.admin, Addr::unchecked(ADMIN_ADDRESS));
+        assert_eq!(state.pending_admin, None);
     }
 
     fn setup_asset_marker() -> QueryMarkerResponse {
@@ -942,8 +3774,39 @@ mod tests {
         }
     }
 
+    fn setup_restricted_asset_marker(transfer_address: &str) -> QueryMarkerResponse {
+        let expected_marker = MarkerAccount {
+            base_account: Some(BaseAccount {
+                address: "tp1l330sxue4suxz9dhc40e2pns0ymrytf8uz4squ".to_string(),
+                pub_key: None,
+                account_number: 11,
+                sequence: 0,
+            }),
+            manager: "".to_string(),
+            access_control: vec![AccessGrant {
+                address: transfer_address.to_string(),
+                permissions: vec![Access::Transfer.into()],
+            }],
+            status: MarkerStatus::Active.into(),
+            denom: ASSET_DENOM.to_string(),
+            supply: "1000".to_string(),
+            marker_type: MarkerType::Restricted.into(),
+            supply_fixed: false,
+            allow_governance_control: true,
+            allow_forced_transfer: false,
+            required_attributes: vec!["loan.kyc.pb".to_string()],
+        };
+
+        QueryMarkerResponse {
+            marker: Some(Any {
+                type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                value: expected_marker.encode_to_vec(),
+            }),
+        }
+    }
+
     fn setup_sale_base(storage: &mut dyn Storage, sale: &Sale) {
-        if let Err(error) = get_sale_storage(storage).save(sale.id.as_bytes(), &sale) {
+        if let Err(error) = save_sale(storage, sale) {
             panic!("unexpected error: {:?}", error)
         }
     }
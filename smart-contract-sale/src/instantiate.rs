@@ -26,6 +26,8 @@ pub fn instantiate(
         admin: info.sender.clone(),
         dcc_denom: msg.dcc_denom.clone(),
         dcc_address: deps.api.addr_validate(&msg.dcc_address)?,
+        pending_admin: None,
+        fee_config: None,
     };
 
     config(deps.storage).save(&contract_info)?;
@@ -74,6 +76,8 @@ mod tests {
                     admin: info.sender.into(),
                     dcc_address: Addr::unchecked(init_message.dcc_address),
                     dcc_denom: "usdf.c".into(),
+                    pending_admin: None,
+                    fee_config: None,
                 };
 
                 assert_eq!(init_response.attributes[0], attr("action", "init"));
@@ -1,21 +1,35 @@
-use cosmwasm_std::{entry_point, DepsMut, Env, Response};
+use cosmwasm_std::{entry_point, DepsMut, Env, Order, Response, StdResult};
 use cw2::set_contract_version;
 
 use crate::contract::{CRATE_NAME, PACKAGE_VERSION};
 use crate::error::ContractError;
 use crate::msg::MigrateMsg;
+use crate::state::{get_sale_storage_read, save_sale, Sale};
 
 #[entry_point]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CRATE_NAME, PACKAGE_VERSION)?;
+
+    // index sales stored before the owner, buyer and status indexes
+    let sales = get_sale_storage_read(deps.storage)
+        .range(None, None, Order::Ascending)
+        .map(|item| item.map(|(_, sale)| sale))
+        .collect::<StdResult<Vec<Sale>>>()?;
+    for sale in sales.iter() {
+        save_sale(deps.storage, sale)?;
+    }
+
     Ok(Response::default())
 }
 
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Addr, Coin, Uint128};
     use provwasm_mocks::mock_provenance_dependencies;
 
+    use crate::state::{get_sale_storage, sales_by_owner_read, sales_by_status_read, Status};
+
     use super::*;
 
     #[test]
@@ -41,4 +55,51 @@ mod tests {
             error => panic!("failed to initialize: {:?}", error),
         }
     }
+
+    #[test]
+    fn migrate_indexes_sales() {
+        let mut deps = mock_provenance_dependencies();
+        let id = "1bd75dc7-5c15-4ef8-9d8c-de9c106d1fdc";
+        let owner = Addr::unchecked("tp1vxyyagf9p7se5rhh9vxs39tswr0srckusjq585");
+
+        // store a sale without indexes
+        get_sale_storage(&mut deps.storage)
+            .save(
+                id.as_bytes(),
+                &Sale {
+                    id: id.into(),
+                    asset: vec![Coin {
+                        amount: Uint128::new(1),
+                        denom: "ibc/loan1".into(),
+                    }],
+                    owner: owner.clone(),
+                    buyer: Some(Addr::unchecked("tp1gjuyjcgxhmv4d003ja576dmzfeu5pjjex7h5k3")),
+                    price: Coin {
+                        amount: Uint128::new(100),
+                        denom: "usdf.c".into(),
+                    },
+                    status: Status::Pending,
+                    funded: false,
+                    expires_at: None,
+                    restricted: false,
+                    allowed_buyers: vec![],
+                    quantity: Uint128::one(),
+                    remaining: Uint128::one(),
+                    version: 0,
+                },
+            )
+            .unwrap();
+
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+        // verify the sale is indexed
+        assert!(sales_by_owner_read(&deps.storage, &owner)
+            .may_load(id.as_bytes())
+            .unwrap()
+            .is_some());
+        assert!(sales_by_status_read(&deps.storage, &Status::Pending)
+            .may_load(id.as_bytes())
+            .unwrap()
+            .is_some());
+    }
 }
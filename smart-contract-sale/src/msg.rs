@@ -1,4 +1,5 @@
 use crate::error::ContractError;
+use crate::state::{Expiration, Sale, Status};
 use cosmwasm_std::{Coin, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -56,14 +57,57 @@ pub enum ExecuteMsg {
     CreateSale {
         id: String,
         price: Coin,
-        buyer: String,
+        // not set for open offers
+        buyer: Option<String>,
+        // buyers allowed to fill an open offer, any buyer if not set
+        allowed_buyers: Option<Vec<String>>,
+        // number of equal lots for partial fills, defaults to one
+        quantity: Option<Uint128>,
+        expires_at: Option<Expiration>,
+        // restricted marker assets to custody by marker transfer instead of sent funds
+        asset: Option<Vec<Coin>>,
+    },
+    FundSale {
+        id: String,
+    },
+    UpdateSale {
+        id: String,
+        price: Coin,
+        // if not set, the buyer is unchanged
+        buyer: Option<String>,
     },
     CompleteSale {
         id: String,
+        // number of lots to fill, defaults to the remaining lots
+        quantity: Option<Uint128>,
+        // if set, the sale version must match to guard against amendments
+        expected_version: Option<u32>,
+        // if set, the sale price must match to guard against amendments
+        expected_price: Option<Coin>,
     },
     CancelSale {
         id: String,
     },
+    RejectSale {
+        id: String,
+        reason: String,
+    },
+    ReclaimExpired {
+        id: String,
+    },
+    ProposeAdmin {
+        id: String,
+        expires_at: Option<Uint128>,
+    },
+    AcceptAdmin {},
+    CancelAdminProposal {},
+    UpdateFeeConfig {
+        collector: String,
+        // fee in basis points of the price, at most 10000
+        bps: u16,
+        // flat minimum fee in dcc token
+        min_fee: Option<Uint128>,
+    },
 }
 
 impl Validate for ExecuteMsg {
@@ -83,7 +127,15 @@ impl Validate for ExecuteMsg {
         let mut invalid_fields: Vec<&str> = vec![];
 
         match self {
-            ExecuteMsg::CreateSale { id, price, buyer } => {
+            ExecuteMsg::CreateSale {
+                id,
+                price,
+                buyer,
+                allowed_buyers,
+                quantity,
+                asset,
+                ..
+            } => {
                 if Uuid::parse_str(id).is_err() {
                     invalid_fields.push("id");
                 }
@@ -96,20 +148,114 @@ impl Validate for ExecuteMsg {
                     invalid_fields.push("price_amount");
                 }
 
-                if buyer.is_empty() {
+                if buyer.as_ref().is_some_and(|buyer| buyer.is_empty()) {
                     invalid_fields.push("buyer");
                 }
+
+                if let Some(allowed_buyers) = allowed_buyers {
+                    if buyer.is_some()
+                        || allowed_buyers.is_empty()
+                        || allowed_buyers.iter().any(|buyer| buyer.is_empty())
+                    {
+                        invalid_fields.push("allowed_buyers");
+                    }
+                }
+
+                if quantity.is_some_and(|quantity| quantity.is_zero()) {
+                    invalid_fields.push("quantity");
+                }
+
+                if let Some(asset) = asset {
+                    if asset.is_empty() {
+                        invalid_fields.push("asset");
+                    }
+
+                    if asset.iter().any(|coin| coin.denom.is_empty()) {
+                        invalid_fields.push("asset_denom");
+                    }
+
+                    if asset.iter().any(|coin| coin.amount <= Uint128::zero()) {
+                        invalid_fields.push("asset_amount");
+                    }
+                }
             }
-            ExecuteMsg::CompleteSale { id } => {
+            ExecuteMsg::FundSale { id } => {
                 if Uuid::parse_str(id).is_err() {
                     invalid_fields.push("id");
                 }
             }
+            ExecuteMsg::UpdateSale { id, price, buyer } => {
+                if Uuid::parse_str(id).is_err() {
+                    invalid_fields.push("id");
+                }
+
+                if price.denom.is_empty() {
+                    invalid_fields.push("price_denom");
+                }
+
+                if price.amount <= Uint128::zero() {
+                    invalid_fields.push("price_amount");
+                }
+
+                if buyer.as_ref().is_some_and(|buyer| buyer.is_empty()) {
+                    invalid_fields.push("buyer");
+                }
+            }
+            ExecuteMsg::CompleteSale {
+                id,
+                quantity,
+                expected_price,
+                ..
+            } => {
+                if Uuid::parse_str(id).is_err() {
+                    invalid_fields.push("id");
+                }
+
+                if quantity.is_some_and(|quantity| quantity.is_zero()) {
+                    invalid_fields.push("quantity");
+                }
+
+                if expected_price
+                    .as_ref()
+                    .is_some_and(|price| price.denom.is_empty())
+                {
+                    invalid_fields.push("expected_price");
+                }
+            }
             ExecuteMsg::CancelSale { id } => {
                 if Uuid::parse_str(id).is_err() {
                     invalid_fields.push("id");
                 }
             }
+            ExecuteMsg::RejectSale { id, reason } => {
+                if Uuid::parse_str(id).is_err() {
+                    invalid_fields.push("id");
+                }
+                if reason.is_empty() {
+                    invalid_fields.push("reason");
+                }
+            }
+            ExecuteMsg::ReclaimExpired { id } => {
+                if Uuid::parse_str(id).is_err() {
+                    invalid_fields.push("id");
+                }
+            }
+            ExecuteMsg::ProposeAdmin { id, .. } => {
+                if id.is_empty() {
+                    invalid_fields.push("id");
+                }
+            }
+            ExecuteMsg::AcceptAdmin {} => {}
+            ExecuteMsg::CancelAdminProposal {} => {}
+            ExecuteMsg::UpdateFeeConfig { collector, bps, .. } => {
+                if collector.is_empty() {
+                    invalid_fields.push("collector");
+                }
+
+                if *bps > 10_000 {
+                    invalid_fields.push("bps");
+                }
+            }
         }
 
         match invalid_fields.len() {
@@ -124,6 +270,10 @@ impl Validate for ExecuteMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum DCCWasmMsg {
+    Transfer {
+        amount: Uint128,
+        recipient: String,
+    },
     ExecutorTransfer {
         amount: Uint128,
         sender: String,
@@ -134,7 +284,27 @@ pub enum DCCWasmMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    GetSale { id: String },
+    GetSale {
+        id: String,
+    },
+    // list sales created by an owner in pages ordered by ID
+    ListSalesByOwner {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // list sales naming, allowing or filled by a buyer in pages ordered by ID
+    ListSalesByBuyer {
+        buyer: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // list sales in pages ordered by ID, optionally in a status
+    ListSales {
+        status: Option<Status>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     GetContractInfo {},
     GetVersionInfo {},
 }
@@ -161,6 +331,31 @@ impl Validate for QueryMsg {
                     invalid_fields.push("id");
                 }
             }
+            QueryMsg::ListSalesByOwner {
+                owner, start_after, ..
+            } => {
+                if owner.is_empty() {
+                    invalid_fields.push("owner");
+                }
+                if !is_valid_start_after(start_after) {
+                    invalid_fields.push("start_after");
+                }
+            }
+            QueryMsg::ListSalesByBuyer {
+                buyer, start_after, ..
+            } => {
+                if buyer.is_empty() {
+                    invalid_fields.push("buyer");
+                }
+                if !is_valid_start_after(start_after) {
+                    invalid_fields.push("start_after");
+                }
+            }
+            QueryMsg::ListSales { start_after, .. } => {
+                if !is_valid_start_after(start_after) {
+                    invalid_fields.push("start_after");
+                }
+            }
             QueryMsg::GetContractInfo {} => {}
             QueryMsg::GetVersionInfo {} => {}
         }
@@ -174,6 +369,21 @@ impl Validate for QueryMsg {
     }
 }
 
+fn is_valid_start_after(start_after: &Option<String>) -> bool {
+    match start_after {
+        Some(id) => Uuid::parse_str(id).is_ok(),
+        None => true,
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Sales {
+    pub sales: Vec<Sale>,
+    // the sale ID to start the next page after, if there are more sales
+    pub next_key: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,11 +414,18 @@ mod tests {
     fn validate_execute_create_sale() {
         let invalid_create_sale_msg = ExecuteMsg::CreateSale {
             id: "gfewa".into(),
-            buyer: "".into(),
+            buyer: Some("".into()),
+            allowed_buyers: Some(vec![]),
+            quantity: Some(Uint128::zero()),
             price: Coin {
                 amount: Uint128::zero(),
                 denom: "".into(),
             },
+            expires_at: None,
+            asset: Some(vec![Coin {
+                amount: Uint128::zero(),
+                denom: "".into(),
+            }]),
         };
 
         let validate_response = invalid_create_sale_msg.validate();
@@ -217,11 +434,15 @@ mod tests {
             Ok(..) => panic!("expected error but was ok"),
             Err(error) => match error {
                 ContractError::InvalidFields { fields } => {
-                    assert_eq!(4, fields.len());
+                    assert_eq!(8, fields.len());
                     assert!(fields.contains(&"id".into()));
                     assert!(fields.contains(&"buyer".into()));
+                    assert!(fields.contains(&"allowed_buyers".into()));
+                    assert!(fields.contains(&"quantity".into()));
                     assert!(fields.contains(&"price_denom".into()));
                     assert!(fields.contains(&"price_amount".into()));
+                    assert!(fields.contains(&"asset_denom".into()));
+                    assert!(fields.contains(&"asset_amount".into()));
                 }
                 error => panic!("unexpected error: {:?}", error),
             },
@@ -230,9 +451,63 @@ mod tests {
 
     #[test]
     fn validate_execute_complete_sale() {
-        let invalid_complete_sale_msg = ExecuteMsg::CompleteSale { id: "asdf".into() };
+        let invalid_complete_sale_msg = ExecuteMsg::CompleteSale {
+            id: "asdf".into(),
+            quantity: Some(Uint128::zero()),
+            expected_version: None,
+            expected_price: Some(Coin {
+                amount: Uint128::new(100),
+                denom: "".into(),
+            }),
+        };
         let validate_response = invalid_complete_sale_msg.validate();
 
+        match validate_response {
+            Ok(..) => panic!("expected error but was ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert_eq!(3, fields.len());
+                    assert!(fields.contains(&"id".into()));
+                    assert!(fields.contains(&"quantity".into()));
+                    assert!(fields.contains(&"expected_price".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_execute_update_sale() {
+        let invalid_update_sale_msg = ExecuteMsg::UpdateSale {
+            id: "asdf".into(),
+            price: Coin {
+                amount: Uint128::zero(),
+                denom: "".into(),
+            },
+            buyer: Some("".into()),
+        };
+        let validate_response = invalid_update_sale_msg.validate();
+
+        match validate_response {
+            Ok(..) => panic!("expected error but was ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert_eq!(4, fields.len());
+                    assert!(fields.contains(&"id".into()));
+                    assert!(fields.contains(&"price_denom".into()));
+                    assert!(fields.contains(&"price_amount".into()));
+                    assert!(fields.contains(&"buyer".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_execute_fund_sale() {
+        let invalid_fund_sale_msg = ExecuteMsg::FundSale { id: "asdf".into() };
+        let validate_response = invalid_fund_sale_msg.validate();
+
         match validate_response {
             Ok(..) => panic!("expected error but was ok"),
             Err(error) => match error {
@@ -262,6 +537,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn validate_execute_reclaim_expired() {
+        let invalid_reclaim_msg = ExecuteMsg::ReclaimExpired { id: "asdf".into() };
+        let validate_response = invalid_reclaim_msg.validate();
+
+        match validate_response {
+            Ok(..) => panic!("expected error but was ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert_eq!(1, fields.len());
+                    assert!(fields.contains(&"id".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_execute_reject_sale() {
+        let invalid_reject_sale_msg = ExecuteMsg::RejectSale {
+            id: "fdsa".into(),
+            reason: "".into(),
+        };
+        let validate_response = invalid_reject_sale_msg.validate();
+
+        match validate_response {
+            Ok(..) => panic!("expected error but was ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert_eq!(2, fields.len());
+                    assert!(fields.contains(&"id".into()));
+                    assert!(fields.contains(&"reason".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_execute_update_fee_config() {
+        let invalid_update_fee_config_msg = ExecuteMsg::UpdateFeeConfig {
+            collector: "".into(),
+            bps: 10_001,
+            min_fee: None,
+        };
+        let validate_response = invalid_update_fee_config_msg.validate();
+
+        match validate_response {
+            Ok(..) => panic!("expected error but was ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert_eq!(2, fields.len());
+                    assert!(fields.contains(&"collector".into()));
+                    assert!(fields.contains(&"bps".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
     #[test]
     fn validate_query_get_sale() {
         let invalid_query_sale_msg = QueryMsg::GetSale { id: "asdf".into() };
@@ -278,4 +613,26 @@ mod tests {
             },
         }
     }
+
+    #[test]
+    fn validate_query_list_sales_by_owner() {
+        let invalid_query_msg = QueryMsg::ListSalesByOwner {
+            owner: "".into(),
+            start_after: Some("asdf".into()),
+            limit: None,
+        };
+        let validate_response = invalid_query_msg.validate();
+
+        match validate_response {
+            Ok(..) => panic!("expected error but was ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert_eq!(2, fields.len());
+                    assert!(fields.contains(&"owner".into()));
+                    assert!(fields.contains(&"start_after".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
 }
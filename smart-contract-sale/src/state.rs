@@ -1,14 +1,17 @@
-use cosmwasm_std::{Addr, Coin, Storage};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Empty, StdResult, Storage, Timestamp, Uint128};
 use cosmwasm_storage::{
     bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket, ReadonlySingleton,
     Singleton,
 };
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 
 pub static CONFIG_KEY: &[u8] = b"config";
 pub static NAMESPACE_SALE: &[u8] = b"sale";
+pub static NAMESPACE_SALE_BY_OWNER: &[u8] = b"sale_owner";
+pub static NAMESPACE_SALE_BY_BUYER: &[u8] = b"sale_buyer";
+pub static NAMESPACE_SALE_BY_STATUS: &[u8] = b"sale_status";
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -16,25 +19,118 @@ pub struct State {
     pub admin: Addr,
     pub dcc_address: Addr,
     pub dcc_denom: String,
+    #[serde(default)]
+    pub pending_admin: Option<PendingAdmin>,
+    // marketplace fee charged on sale completion, no fee if not set
+    #[serde(default)]
+    pub fee_config: Option<FeeConfig>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingAdmin {
+    pub address: Addr,
+    // block height after which the proposal can no longer be accepted
+    pub expires_at: Option<Uint128>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeeConfig {
+    // address the fee in dcc token is sent to
+    pub collector: Addr,
+    // fee in basis points of the price
+    pub bps: u16,
+    // flat minimum fee, no minimum if not set
+    pub min_fee: Option<Uint128>,
+}
+
+impl FeeConfig {
+    // the fee on a price amount, never more than the price
+    pub fn fee(&self, amount: Uint128) -> Uint128 {
+        let fee = amount.multiply_ratio(self.bps, 10_000u128);
+        fee.max(self.min_fee.unwrap_or_default()).min(amount)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct Sale {
     pub id: String,
-    pub asset: Coin,
+    // basket of coins sold together, sales created before baskets hold a single coin
+    #[serde(deserialize_with = "one_or_many")]
+    pub asset: Vec<Coin>,
     pub owner: Addr,
-    pub buyer: Addr,
+    // not set for open offers
+    pub buyer: Option<Addr>,
     pub price: Coin,
     pub status: Status,
+    // whether the buyer has escrowed the price at the contract
+    #[serde(default)]
+    pub funded: bool,
+    // after which the sale can no longer be completed
+    #[serde(default)]
+    pub expires_at: Option<Expiration>,
+    // whether the asset is a restricted marker held through marker transfers
+    #[serde(default)]
+    pub restricted: bool,
+    // buyers allowed to fill an open offer, any buyer if empty
+    #[serde(default)]
+    pub allowed_buyers: Vec<Addr>,
+    // number of equal lots the asset and price are split into for partial fills
+    #[serde(default = "default_quantity")]
+    pub quantity: Uint128,
+    // number of lots not yet filled
+    #[serde(default = "default_quantity")]
+    pub remaining: Uint128,
+    // number of times the sale terms have been amended
+    #[serde(default)]
+    pub version: u32,
+}
+
+fn default_quantity() -> Uint128 {
+    Uint128::one()
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(Coin),
+    Many(Vec<Coin>),
+}
+
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Coin>, D::Error> {
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(coin) => vec![coin],
+        OneOrMany::Many(coins) => coins,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Expiration {
+    AtHeight(Uint128),
+    AtTime(Timestamp),
+}
+
+impl Expiration {
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        match self {
+            Expiration::AtHeight(height) => Uint128::from(block.height) >= *height,
+            Expiration::AtTime(time) => block.time >= *time,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Status {
     Pending,
+    PartiallyFilled,
     Canceled,
     Complete,
+    Expired,
+    Rejected,
 }
 
 impl fmt::Display for Status {
@@ -58,3 +154,72 @@ pub fn get_sale_storage(storage: &mut dyn Storage) -> Bucket<Sale> {
 pub fn get_sale_storage_read(storage: &dyn Storage) -> ReadonlyBucket<Sale> {
     bucket_read(storage, NAMESPACE_SALE)
 }
+
+// sale ids by owner
+pub fn sales_by_owner<'a>(storage: &'a mut dyn Storage, owner: &Addr) -> Bucket<'a, Empty> {
+    Bucket::multilevel(storage, &[NAMESPACE_SALE_BY_OWNER, owner.as_bytes()])
+}
+
+pub fn sales_by_owner_read<'a>(
+    storage: &'a dyn Storage,
+    owner: &Addr,
+) -> ReadonlyBucket<'a, Empty> {
+    ReadonlyBucket::multilevel(storage, &[NAMESPACE_SALE_BY_OWNER, owner.as_bytes()])
+}
+
+// sale ids by named, allowed or filling buyer
+pub fn sales_by_buyer<'a>(storage: &'a mut dyn Storage, buyer: &Addr) -> Bucket<'a, Empty> {
+    Bucket::multilevel(storage, &[NAMESPACE_SALE_BY_BUYER, buyer.as_bytes()])
+}
+
+pub fn sales_by_buyer_read<'a>(
+    storage: &'a dyn Storage,
+    buyer: &Addr,
+) -> ReadonlyBucket<'a, Empty> {
+    ReadonlyBucket::multilevel(storage, &[NAMESPACE_SALE_BY_BUYER, buyer.as_bytes()])
+}
+
+// sale ids by status
+pub fn sales_by_status<'a>(storage: &'a mut dyn Storage, status: &Status) -> Bucket<'a, Empty> {
+    Bucket::multilevel(
+        storage,
+        &[NAMESPACE_SALE_BY_STATUS, status.to_string().as_bytes()],
+    )
+}
+
+pub fn sales_by_status_read<'a>(
+    storage: &'a dyn Storage,
+    status: &Status,
+) -> ReadonlyBucket<'a, Empty> {
+    ReadonlyBucket::multilevel(
+        storage,
+        &[NAMESPACE_SALE_BY_STATUS, status.to_string().as_bytes()],
+    )
+}
+
+// save a sale and keep its indexes consistent
+pub fn save_sale(storage: &mut dyn Storage, sale: &Sale) -> StdResult<()> {
+    let key = sale.id.as_bytes();
+
+    if let Some(existing) = get_sale_storage_read(storage).may_load(key)? {
+        if existing.status != sale.status {
+            sales_by_status(storage, &existing.status).remove(key);
+        }
+        if let Some(buyer) = existing
+            .buyer
+            .filter(|buyer| sale.buyer.as_ref() != Some(buyer))
+        {
+            sales_by_buyer(storage, &buyer).remove(key);
+        }
+    }
+
+    get_sale_storage(storage).save(key, sale)?;
+    sales_by_owner(storage, &sale.owner).save(key, &Empty {})?;
+    if let Some(buyer) = &sale.buyer {
+        sales_by_buyer(storage, buyer).save(key, &Empty {})?;
+    }
+    for buyer in sale.allowed_buyers.iter() {
+        sales_by_buyer(storage, buyer).save(key, &Empty {})?;
+    }
+    sales_by_status(storage, &sale.status).save(key, &Empty {})
+}
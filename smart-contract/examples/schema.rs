@@ -3,8 +3,17 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
+use dcc::denom::Denom;
+use dcc::event::CoinMovement;
+use dcc::executor::{Allowance, AuthorizationPayload, ExecutorConfig};
+use dcc::freeze::{ForceTransfer, FrozenAddress};
+use dcc::join_proposal::JoinProposalV2;
 use dcc::member::MemberV2;
-use dcc::msg::{ExecuteMsg, InitMsg, Members, QueryMsg};
+use dcc::mint::{MintApprovalConfig, MintRecord, PendingMint};
+use dcc::msg::{
+    ExecuteMsg, ForceTransfers, InitMsg, MemberSupply, Members, PendingMints, QueryMsg, Redemptions,
+};
+use dcc::redemption::Redemption;
 use dcc::state::StateV2;
 
 fn main() {
@@ -19,4 +28,20 @@ fn main() {
     export_schema(&schema_for!(StateV2), &out_dir);
     export_schema(&schema_for!(MemberV2), &out_dir);
     export_schema(&schema_for!(Members), &out_dir);
+    export_schema(&schema_for!(MemberSupply), &out_dir);
+    export_schema(&schema_for!(JoinProposalV2), &out_dir);
+    export_schema(&schema_for!(FrozenAddress), &out_dir);
+    export_schema(&schema_for!(ForceTransfer), &out_dir);
+    export_schema(&schema_for!(ForceTransfers), &out_dir);
+    export_schema(&schema_for!(ExecutorConfig), &out_dir);
+    export_schema(&schema_for!(Allowance), &out_dir);
+    export_schema(&schema_for!(AuthorizationPayload), &out_dir);
+    export_schema(&schema_for!(Denom), &out_dir);
+    export_schema(&schema_for!(CoinMovement), &out_dir);
+    export_schema(&schema_for!(MintRecord), &out_dir);
+    export_schema(&schema_for!(MintApprovalConfig), &out_dir);
+    export_schema(&schema_for!(PendingMint), &out_dir);
+    export_schema(&schema_for!(PendingMints), &out_dir);
+    export_schema(&schema_for!(Redemption), &out_dir);
+    export_schema(&schema_for!(Redemptions), &out_dir);
 }
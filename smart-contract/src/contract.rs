@@ -1,11 +1,12 @@
 use std::convert::TryFrom;
 
 use cosmwasm_std::{
-    entry_point, to_binary, Addr, Deps, DepsMut, Empty, Env, MessageInfo, Order, QueryResponse,
-    Response, StdError, StdResult, Uint128,
+    entry_point, to_binary, to_vec, Addr, Decimal, Deps, DepsMut, Empty, Env, Event, MessageInfo,
+    Order, QueryResponse, Response, StdError, StdResult, Storage, Uint128,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use provwasm_std::types::{
+    cosmos::base::query::v1beta1::PageRequest,
     cosmos::base::v1beta1::Coin,
     provenance::attribute::v1::AttributeQuerier,
     provenance::marker::v1::{
@@ -15,18 +16,52 @@ use provwasm_std::types::{
     },
 };
 use semver::Version;
+use sha2::{Digest, Sha256};
 
+use crate::denom::{
+    denom_max_supplies, denom_max_supplies_read, denom_supplies, denom_supplies_read, denoms,
+    denoms_read, migrate_denoms, Denom,
+};
 use crate::error::ContractError;
-use crate::join_proposal::migrate_join_proposals;
-use crate::member::{members, members_read, migrate_members, MemberV2};
-use crate::msg::{ExecuteMsg, InitMsg, Members, MigrateMsg, QueryMsg};
-use crate::state::{config, config_read, migrate_state, StateV2};
+use crate::event::{iso8601, CoinMovement, CoinMovementType};
+use crate::executor::{
+    allowances, allowances_read, authorization_nonces, authorization_nonces_read, executor_configs,
+    executor_configs_read, executor_spends, executor_spends_read, pub_key_address, Allowance,
+    AuthorizationPayload, ExecutorConfig, SenderScope, TransferAuthorization,
+};
+use crate::freeze::{
+    force_transfer_seq, force_transfer_seq_read, force_transfers, force_transfers_read,
+    frozen_addresses, frozen_addresses_read, is_frozen, ForceTransfer, FrozenAddress,
+};
+use crate::join_proposal::{
+    join_proposals, join_proposals_read, migrate_join_proposals, JoinProposalV2,
+};
+use crate::member::{
+    default_weight, kyc_attr_index, kyc_attr_index_read, members, members_read, migrate_members,
+    MemberV2,
+};
+use crate::mint::{
+    mint_approval_configs, mint_approval_configs_read, mint_references, mint_references_read,
+    pending_mint_references, pending_mint_references_read, pending_mint_seq, pending_mint_seq_read,
+    pending_mints, pending_mints_read, MintApprovalConfig, MintRecord, PendingMint,
+};
+use crate::msg::{
+    ExecuteMsg, ForceTransfers, InitMsg, MemberSupply, Members, MigrateMsg, PendingMints, QueryMsg,
+    Redemptions, VoteChoice,
+};
+use crate::redemption::{redemptions, redemptions_read, Redemption};
+use crate::state::{
+    config, config_read, default_quorum_pct, default_vote_duration, migrate_state, PauseScope,
+    PausedScope, PendingAdmin, StateV2,
+};
 
 // Contract constants
 pub static CONTRACT_NAME: &str = env!("CARGO_CRATE_NAME");
 pub static CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub static MIN_DENOM_LEN: usize = 8;
 pub static MIN_NAME_LEN: usize = 4;
+pub static DEFAULT_QUERY_LIMIT: u32 = 10;
+pub static MAX_QUERY_LIMIT: u32 = 30;
 
 /// Create the initial configuration state and propose the marker.
 #[entry_point]
@@ -38,7 +73,7 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     // Validate params
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during instantiate"));
+        return Err(ContractError::FundsNotAllowed {});
     }
 
     // Create and store config state.
@@ -46,8 +81,14 @@ pub fn instantiate(
         admin: info.sender.clone(),
         denom: msg.denom.clone(),
         executors: vec![],
+        removal_delay: Uint128::zero(),
+        pending_admin: None,
+        quorum_pct: default_quorum_pct(),
+        vote_duration: default_vote_duration(),
+        paused: vec![],
     };
     config(deps.storage).save(&state)?;
+    denoms(deps.storage).save(msg.denom.as_bytes(), &Denom::enabled(msg.denom.clone()))?;
 
     // Create the marker and grant permissions if it doesn't exist.
     let mut res = Response::new();
@@ -57,38 +98,7 @@ pub fn instantiate(
             return Err(contract_err("invalid denom length"));
         }
 
-        res = res.add_message(MsgAddFinalizeActivateMarkerRequest {
-            amount: Some(Coin {
-                denom: msg.denom.clone(),
-                amount: "0".to_string(),
-            }),
-            manager: env.contract.address.to_string(),
-            from_address: env.contract.address.to_string(),
-            marker_type: MarkerType::Restricted.into(),
-            access_list: vec![
-                AccessGrant {
-                    address: env.contract.address.to_string(),
-                    permissions: vec![
-                        Access::Mint.into(),
-                        Access::Burn.into(),
-                        Access::Deposit.into(),
-                        Access::Withdraw.into(),
-                        Access::Delete.into(),
-                        Access::Admin.into(),
-                        Access::Transfer.into(),
-                    ],
-                },
-                AccessGrant {
-                    address: info.sender.to_string(),
-                    // The contract admin is also a marker admin
-                    permissions: vec![Access::Admin.into()],
-                },
-            ],
-            supply_fixed: false,
-            allow_governance_control: false,
-            allow_forced_transfer: false,
-            required_attributes: vec![],
-        });
+        res = res.add_message(create_marker_request(&env, &info.sender, &msg.denom));
     }
 
     // Set contract version.
@@ -97,6 +107,47 @@ pub fn instantiate(
     Ok(res)
 }
 
+// Create a restricted marker for the denom, managed by the contract.
+fn create_marker_request(
+    env: &Env,
+    admin: &Addr,
+    denom: &str,
+) -> MsgAddFinalizeActivateMarkerRequest {
+    MsgAddFinalizeActivateMarkerRequest {
+        amount: Some(Coin {
+            denom: denom.to_string(),
+            amount: "0".to_string(),
+        }),
+        manager: env.contract.address.to_string(),
+        from_address: env.contract.address.to_string(),
+        marker_type: MarkerType::Restricted.into(),
+        access_list: vec![
+            AccessGrant {
+                address: env.contract.address.to_string(),
+                permissions: vec![
+                    Access::Mint.into(),
+                    Access::Burn.into(),
+                    Access::Deposit.into(),
+                    Access::Withdraw.into(),
+                    Access::Delete.into(),
+                    Access::Admin.into(),
+                    Access::Transfer.into(),
+                ],
+            },
+            AccessGrant {
+                address: admin.to_string(),
+                // The contract admin is also a marker admin
+                permissions: vec![Access::Admin.into()],
+            },
+        ],
+        supply_fixed: false,
+        allow_governance_control: false,
+        // Forced transfers are required to claw back tokens from frozen addresses.
+        allow_forced_transfer: true,
+        required_attributes: vec![],
+    }
+}
+
 // Determine whether the marker with the given denom exists.
 fn marker_exists(deps: Deps, denom: &str) -> bool {
     let querier = MarkerQuerier::new(&deps.querier);
@@ -125,26 +176,134 @@ pub fn execute(
             name,
             kyc_attrs,
         } => try_join(deps, env, info, id, name, kyc_attrs),
-        ExecuteMsg::Remove { id } => try_remove(deps, info, id),
-        ExecuteMsg::Transfer { amount, recipient } => {
-            try_transfer(deps, env, info, amount, recipient)
+        ExecuteMsg::ProposeJoin {
+            id,
+            name,
+            kyc_attrs,
+        } => try_propose_join(deps, env, info, id, name, kyc_attrs),
+        ExecuteMsg::Vote { id, choice } => try_vote(deps, env, info, id, choice),
+        ExecuteMsg::ExecuteProposal { id } => try_execute_proposal(deps, env, info, id),
+        ExecuteMsg::ExpireProposal { id } => try_expire_proposal(deps, env, info, id),
+        ExecuteMsg::Remove { id } => try_remove(deps, env, info, id),
+        ExecuteMsg::FinalizeRemoval { id } => try_finalize_removal(deps, env, info, id),
+        ExecuteMsg::Transfer {
+            amount,
+            recipient,
+            denom,
+        } => try_transfer(deps, env, info, amount, recipient, denom),
+        ExecuteMsg::Mint {
+            amount,
+            address,
+            denom,
+            reference,
+        } => try_mint(deps, env, info, amount, address, denom, reference),
+        ExecuteMsg::Burn { amount, denom } => try_burn(deps, env, info, amount, denom),
+        ExecuteMsg::Redeem {
+            amount,
+            member_id,
+            reference,
+            denom,
+        } => try_redeem(deps, env, info, amount, member_id, reference, denom),
+        ExecuteMsg::SettleRedemption { reference } => {
+            try_settle_redemption(deps, env, info, reference)
+        }
+        ExecuteMsg::RejectRedemption { reference } => {
+            try_reject_redemption(deps, env, info, reference)
         }
-        ExecuteMsg::Mint { amount, address } => try_mint(deps, env, info, amount, address),
-        ExecuteMsg::Burn { amount } => try_burn(deps, env, info, amount),
         ExecuteMsg::AddKyc { id, kyc_attr } => try_add_kyc(deps, info, id, kyc_attr),
         ExecuteMsg::RemoveKyc { id, kyc_attr } => try_remove_kyc(deps, info, id, kyc_attr),
-        ExecuteMsg::SetAdmin { id } => try_set_admin(deps, info, id),
+        ExecuteMsg::ProposeAdmin { id, expires_at } => {
+            try_propose_admin(deps, env, info, id, expires_at)
+        }
+        ExecuteMsg::AcceptAdmin {} => try_accept_admin(deps, env, info),
+        ExecuteMsg::CancelAdminProposal {} => try_cancel_admin_proposal(deps, info),
         ExecuteMsg::AddExecutor { id } => try_add_executor(deps, info, id),
         ExecuteMsg::RemoveExecutor { id } => try_remove_executor(deps, info, id),
         ExecuteMsg::ExecutorTransfer {
             amount,
             sender,
             recipient,
-        } => try_executor_transfer(deps, env, info, amount, sender, recipient),
+            authorization,
+            denom,
+        } => try_executor_transfer(
+            deps,
+            env,
+            info,
+            amount,
+            sender,
+            recipient,
+            authorization,
+            denom,
+        ),
+        ExecuteMsg::SetExecutorConfig {
+            id,
+            sender_scope,
+            max_per_tx,
+            window_blocks,
+            window_cap,
+        } => try_set_executor_config(
+            deps,
+            info,
+            id,
+            sender_scope,
+            max_per_tx,
+            window_blocks,
+            window_cap,
+        ),
+        ExecuteMsg::IncreaseAllowance {
+            executor,
+            amount,
+            denom,
+        } => try_increase_allowance(deps, info, executor, amount, denom),
+        ExecuteMsg::DecreaseAllowance {
+            executor,
+            amount,
+            denom,
+        } => try_decrease_allowance(deps, info, executor, amount, denom),
+        ExecuteMsg::SetMemberLimit {
+            id,
+            max_supply,
+            denom,
+        } => try_set_member_limit(deps, info, id, max_supply, denom),
+        ExecuteMsg::SetMintApproval {
+            id,
+            threshold,
+            approver,
+            window_blocks,
+        } => try_set_mint_approval(deps, info, id, threshold, approver, window_blocks),
+        ExecuteMsg::ApproveMint { id } => try_approve_mint(deps, env, info, id),
+        ExecuteMsg::RejectMint { id } => try_reject_mint(deps, info, id),
+        ExecuteMsg::ExpirePendingMint { id } => try_expire_pending_mint(deps, env, info, id),
+        ExecuteMsg::SetRemovalDelay { blocks } => try_set_removal_delay(deps, info, blocks),
+        ExecuteMsg::SetMemberWeight { id, weight } => try_set_member_weight(deps, info, id, weight),
+        ExecuteMsg::SetProposalConfig {
+            quorum_pct,
+            vote_duration,
+        } => try_set_proposal_config(deps, info, quorum_pct, vote_duration),
+        ExecuteMsg::Pause {
+            scope,
+            reason,
+            denom,
+        } => try_pause(deps, info, scope, reason, denom),
+        ExecuteMsg::Unpause { scope, denom } => try_unpause(deps, info, scope, denom),
+        ExecuteMsg::FreezeAddress { id, reason } => try_freeze_address(deps, env, info, id, reason),
+        ExecuteMsg::UnfreezeAddress { id } => try_unfreeze_address(deps, info, id),
+        ExecuteMsg::ForceTransfer {
+            amount,
+            sender,
+            recipient,
+            reason,
+            denom,
+        } => try_force_transfer(deps, env, info, amount, sender, recipient, reason, denom),
+        ExecuteMsg::AddDenom { denom } => try_add_denom(deps, env, info, denom),
+        ExecuteMsg::SetDenomEnabled { denom, enabled } => {
+            try_set_denom_enabled(deps, info, denom, enabled)
+        }
     }
 }
 
-// Add a member to the consortium.
+// Add a member to the consortium as admin. This bypasses the member vote of ProposeJoin, and is
+// only meant for bootstrapping the consortium and for emergencies.
 fn try_join(
     deps: DepsMut,
     env: Env,
@@ -155,15 +314,38 @@ fn try_join(
 ) -> Result<Response, ContractError> {
     // Validate params.
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during join"));
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    let valid_attrs = validate_join(&name, &kyc_attrs)?;
+
+    let address = deps.api.addr_validate(&id)?;
+
+    // Read state
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
     }
+
+    ensure_joinable(deps.as_ref(), &address, &valid_attrs)?;
+    add_member(deps, env, &address, name, valid_attrs)?;
+
+    let res = Response::new()
+        .add_attribute("action", "join")
+        .add_attribute("member_id", address);
+    Ok(res)
+}
+
+// Validate the name and kyc attributes of a member to join, returning the trimmed attributes.
+fn validate_join(name: &str, kyc_attrs: &[String]) -> Result<Vec<String>, ContractError> {
     if name.len() < MIN_NAME_LEN {
         return Err(contract_err("invalid name too short"));
     }
     if kyc_attrs.is_empty() {
         return Err(contract_err("at least one kyc attribute is required"));
     }
-    for kyc_attr in &kyc_attrs {
+    for kyc_attr in kyc_attrs {
         if kyc_attr.trim().is_empty() {
             return Err(contract_err("kyc attribute name is empty"));
         }
@@ -179,52 +361,343 @@ fn try_join(
         return Err(contract_err("duplicate kyc attributes in args"));
     }
 
-    let address = deps.api.addr_validate(&id)?;
-    let key = address.as_bytes();
-
-    // Read state
-    let state = config_read(deps.storage).load()?;
-
-    // Ensure message sender is admin.
-    if info.sender != state.admin {
-        return Err(ContractError::Unauthorized {});
-    }
+    Ok(valid_attrs)
+}
 
+// Ensure the address is not a member and its kyc attributes are not owned by another member.
+fn ensure_joinable(deps: Deps, address: &Addr, kyc_attrs: &[String]) -> Result<(), ContractError> {
     // Verify kyc attribute does not already exist
-    let curr_kyc_attrs = get_attributes(deps.as_ref())?;
-    for kyc_attr in &valid_attrs {
-        if curr_kyc_attrs.contains(kyc_attr) {
-            return Err(contract_err("duplicate kyc attribute"));
+    for kyc_attr in kyc_attrs {
+        if kyc_attr_index_read(deps.storage)
+            .may_load(kyc_attr.as_bytes())?
+            .is_some()
+        {
+            return Err(ContractError::DuplicateKycAttr {
+                attr: kyc_attr.clone(),
+            });
         }
     }
 
     // Check for existing member
-    let mut members = members(deps.storage);
-    if members.may_load(key)?.is_some() {
+    if members_read(deps.storage)
+        .may_load(address.as_bytes())?
+        .is_some()
+    {
         return Err(contract_err("duplicate member"));
     }
 
-    members.save(
-        key,
+    Ok(())
+}
+
+// Save a new member and index its kyc attributes.
+fn add_member(
+    deps: DepsMut,
+    env: Env,
+    address: &Addr,
+    name: String,
+    kyc_attrs: Vec<String>,
+) -> Result<(), ContractError> {
+    members(deps.storage).save(
+        address.as_bytes(),
         &MemberV2 {
             id: address.clone(),
             joined: Uint128::from(env.block.height),
             name,
-            kyc_attrs: valid_attrs.clone(),
+            kyc_attrs: kyc_attrs.clone(),
+            supply: Uint128::zero(),
+            max_supply: None,
+            removal_height: None,
+            weight: default_weight(),
         },
     )?;
 
+    // Index the member kyc attributes
+    for kyc_attr in &kyc_attrs {
+        kyc_attr_index(deps.storage).save(kyc_attr.as_bytes(), address)?;
+    }
+
+    Ok(())
+}
+
+// Propose a member to join the consortium, pending member votes.
+fn try_propose_join(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    name: String,
+    kyc_attrs: Vec<String>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    let valid_attrs = validate_join(&name, &kyc_attrs)?;
+
+    let address = deps.api.addr_validate(&id)?;
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin or a member.
+    if info.sender != state.admin
+        && members_read(deps.storage)
+            .may_load(info.sender.as_bytes())?
+            .is_none()
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    ensure_joinable(deps.as_ref(), &address, &valid_attrs)?;
+
+    // Check for existing proposal
+    let mut proposals = join_proposals(deps.storage);
+    if proposals.may_load(address.as_bytes())?.is_some() {
+        return Err(contract_err("duplicate proposal"));
+    }
+
+    let height = Uint128::from(env.block.height);
+    let proposal = JoinProposalV2 {
+        id: address.clone(),
+        name,
+        kyc_attrs: valid_attrs,
+        proposer: info.sender,
+        created: height,
+        expires: height
+            .checked_add(state.vote_duration)
+            .map_err(StdError::from)?,
+        yes: Uint128::zero(),
+        no: Uint128::zero(),
+        voters: vec![],
+    };
+    proposals.save(address.as_bytes(), &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_join")
+        .add_attribute("proposal_id", &proposal.id)
+        .add_attribute("expires", proposal.expires))
+}
+
+// Vote on a join proposal as a member.
+fn try_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    choice: VoteChoice,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    // Ensure message sender is a member.
+    let member = match members_read(deps.storage).may_load(info.sender.as_bytes())? {
+        Some(member) => member,
+        None => return Err(ContractError::Unauthorized {}),
+    };
+    if member.removal_height.is_some() {
+        return Err(ContractError::MemberRemoving {
+            id: member.id.to_string(),
+        });
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+    let mut proposal = match join_proposals_read(deps.storage).may_load(key)? {
+        Some(proposal) => proposal,
+        None => return Err(contract_err("proposal does not exist")),
+    };
+
+    // Ensure the voting window is open.
+    if Uint128::from(env.block.height) > proposal.expires {
+        return Err(contract_err("voting window has closed"));
+    }
+
+    // Ensure the member has not voted.
+    if proposal.voters.contains(&member.id) {
+        return Err(contract_err("member has already voted"));
+    }
+
+    let choice = match choice {
+        VoteChoice::Yes => {
+            proposal.yes += member.weight;
+            "yes"
+        }
+        VoteChoice::No => {
+            proposal.no += member.weight;
+            "no"
+        }
+    };
+    proposal.voters.push(member.id.clone());
+    join_proposals(deps.storage).save(key, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "vote")
+        .add_attribute("proposal_id", &proposal.id)
+        .add_attribute("member_id", &member.id)
+        .add_attribute("choice", choice)
+        .add_attribute("weight", member.weight))
+}
+
+// Add the proposed member once a join proposal has reached quorum.
+fn try_execute_proposal(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+    let proposal = match join_proposals_read(deps.storage).may_load(key)? {
+        Some(proposal) => proposal,
+        None => return Err(contract_err("proposal does not exist")),
+    };
+
+    if !quorum_reached(deps.as_ref(), &proposal)? {
+        return Err(contract_err("proposal has not reached quorum"));
+    }
+
+    // Create the member through the same checks as join.
+    ensure_joinable(deps.as_ref(), &proposal.id, &proposal.kyc_attrs)?;
+    add_member(
+        deps.branch(),
+        env,
+        &proposal.id,
+        proposal.name.clone(),
+        proposal.kyc_attrs.clone(),
+    )?;
+    join_proposals(deps.storage).remove(key);
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_proposal")
+        .add_attribute("proposal_id", &proposal.id)
+        .add_attribute("member_id", &proposal.id))
+}
+
+// Remove a join proposal that did not reach quorum before its voting window closed.
+fn try_expire_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+    let proposal = match join_proposals_read(deps.storage).may_load(key)? {
+        Some(proposal) => proposal,
+        None => return Err(contract_err("proposal does not exist")),
+    };
+
+    // Ensure the voting window has closed.
+    if Uint128::from(env.block.height) <= proposal.expires {
+        return Err(contract_err("voting window has not closed"));
+    }
+
+    // Approved proposals remain executable.
+    if quorum_reached(deps.as_ref(), &proposal)? {
+        return Err(contract_err("proposal has reached quorum"));
+    }
+
+    join_proposals(deps.storage).remove(key);
+
+    Ok(Response::new()
+        .add_attribute("action", "expire_proposal")
+        .add_attribute("proposal_id", &proposal.id))
+}
+
+// Determine whether 'yes' votes meet the quorum percentage of total member weight. Members being
+// removed can no longer vote, so their weight is left out.
+fn quorum_reached(deps: Deps, proposal: &JoinProposalV2) -> Result<bool, ContractError> {
+    let state = config_read(deps.storage).load()?;
+    let total_weight = members_read(deps.storage)
+        .range(None, None, Order::Ascending)
+        .try_fold(Uint128::zero(), |total, item| {
+            item.map(|(_, member)| match member.removal_height {
+                Some(_) => total,
+                None => total + member.weight,
+            })
+        })?;
+
+    if total_weight.is_zero() {
+        return Ok(false);
+    }
+    Ok(Decimal::from_ratio(proposal.yes, total_weight) >= state.quorum_pct)
+}
+
+// Start removing a member from the consortium. While the removal is pending, customers of the
+// member can transfer tokens out, but can no longer receive them.
+fn try_remove(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+
+    // Read state
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut member = match members_read(deps.storage).may_load(key)? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: address.to_string(),
+            })
+        }
+    };
+
+    // Ensure removal wasn't already started.
+    if member.removal_height.is_some() {
+        return Err(contract_err("member is already being removed"));
+    }
+
+    // Ensure the member no longer holds any token.
+    ensure_no_token_balance(deps.as_ref(), &state, &member.id)?;
+
+    // Mark the member as being removed and save
+    let removal_height = Uint128::from(env.block.height)
+        .checked_add(state.removal_delay)
+        .map_err(StdError::from)?;
+    member.removal_height = Some(removal_height);
+    members(deps.storage).save(key, &member)?;
+
     let res = Response::new()
-        .add_attribute("action", "join")
-        .add_attribute("member_id", address.clone());
+        .add_attribute("action", "remove")
+        .add_attribute("member_id", address)
+        .add_attribute("removal_height", removal_height);
     Ok(res)
 }
 
-// Remove a member from the consortium.
-fn try_remove(deps: DepsMut, info: MessageInfo, id: String) -> Result<Response, ContractError> {
+// Finish removing a member from the consortium once the removal delay has passed.
+fn try_finalize_removal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response, ContractError> {
     // Validate params.
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during cancel"));
+        return Err(ContractError::FundsNotAllowed {});
     }
 
     let address = deps.api.addr_validate(&id)?;
@@ -238,18 +711,46 @@ fn try_remove(deps: DepsMut, info: MessageInfo, id: String) -> Result<Response,
         return Err(ContractError::Unauthorized {});
     }
 
-    let mut members = members(deps.storage);
-    if members.may_load(key)?.is_none() {
-        return Err(contract_err("member does not exist"));
+    let member = match members_read(deps.storage).may_load(key)? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: address.to_string(),
+            })
+        }
+    };
+
+    // Ensure the removal delay has passed.
+    match member.removal_height {
+        None => return Err(contract_err("member is not being removed")),
+        Some(height) if Uint128::from(env.block.height) < height => {
+            return Err(contract_err("member removal delay has not passed"));
+        }
+        _ => {}
+    }
+
+    // Ensure the member did not receive any token during removal.
+    ensure_no_token_balance(deps.as_ref(), &state, &member.id)?;
+
+    // Ensure the member settled or rejected its redemptions.
+    if redemptions_read(deps.storage, &member.id)
+        .range(None, None, Order::Ascending)
+        .next()
+        .is_some()
+    {
+        return Err(contract_err("member has open redemptions"));
     }
 
-    // TODO - validate when it is okay to remove a member, including impact on any
-    // addresses holding USDF that belong to member.
-    members.remove(key);
+    members(deps.storage).remove(key);
+
+    // Remove the member kyc attributes from the index
+    for kyc_attr in &member.kyc_attrs {
+        kyc_attr_index(deps.storage).remove(kyc_attr.as_bytes());
+    }
 
     let res = Response::new()
-        .add_attribute("action", "remove")
-        .add_attribute("member_id", address.clone());
+        .add_attribute("action", "finalize_removal")
+        .add_attribute("member_id", address);
     Ok(res)
 }
 
@@ -261,10 +762,11 @@ fn try_transfer(
     info: MessageInfo,
     amount: Uint128,
     recipient: String,
+    denom: Option<String>,
 ) -> Result<Response, ContractError> {
     // Ensure no funds were sent
     if !info.funds.is_empty() {
-        return Err(contract_err("bank sends are not allowed in transfer"));
+        return Err(ContractError::FundsNotAllowed {});
     }
 
     // Ensure amount is non-zero.
@@ -277,29 +779,49 @@ fn try_transfer(
 
     // Read state
     let state = config_read(deps.storage).load()?;
+    let denom = load_active_denom(deps.as_ref(), &state, denom, PauseScope::Transfer)?;
 
     // Ensure the sender holds at least the indicated amount of token.
     let balance = deps
         .querier
-        .query_balance(info.sender.clone(), &state.denom)?;
+        .query_balance(info.sender.clone(), &denom.denom)?;
     if balance.amount < amount {
-        return Err(contract_err("insufficient token balance in transfer"));
+        return Err(ContractError::InsufficientBalance {
+            needed: amount,
+            available: balance.amount,
+        });
+    }
+
+    // Ensure neither account is frozen.
+    if is_frozen(deps.storage, &info.sender)? {
+        return Err(ContractError::AddressFrozen {
+            addr: info.sender.to_string(),
+        });
+    }
+    if is_frozen(deps.storage, &recipient)? {
+        return Err(ContractError::AddressFrozen {
+            addr: recipient.to_string(),
+        });
     }
 
     // Ensure accounts have the required member kyc attribute.
-    let members: Vec<MemberV2> = get_members(deps.as_ref())?;
     let from_member = match members_read(deps.storage).may_load(info.sender.as_bytes())? {
         Some(m) => m,
-        None => matched_member(deps.as_ref(), info.sender.clone(), members.clone())?,
+        None => matched_member(deps.as_ref(), &info.sender)?,
     };
     let to_member = match members_read(deps.storage).may_load(recipient.as_bytes())? {
         Some(m) => m,
-        None => matched_member(deps.as_ref(), recipient.clone(), members)?,
+        None => matched_member(deps.as_ref(), &recipient)?,
     };
 
+    // Ensure the recipient member is not being removed.
+    if to_member.removal_height.is_some() {
+        return Err(contract_err("recipient member is being removed"));
+    }
+
     // Transfer the token
     let coin = Coin {
-        denom: state.denom.clone(),
+        denom: denom.denom.clone(),
         amount: amount.to_string(),
     };
     let res = Response::new()
@@ -309,9 +831,20 @@ fn try_transfer(
             from_address: info.sender.to_string(),
             to_address: recipient.to_string(),
         })
+        .add_event(Event::from(CoinMovement {
+            from_address: info.sender.clone(),
+            from_address_bank_uuid: Some(from_member.id.to_string()),
+            to_address: recipient.clone(),
+            to_address_bank_uuid: Some(to_member.id.to_string()),
+            block_height: env.block.height.into(),
+            timestamp: iso8601(env.block.time),
+            amount,
+            denom: denom.denom.clone(),
+            movement_type: CoinMovementType::Transfer,
+        }))
         .add_attribute("action", "transfer")
         .add_attribute("amount", amount)
-        .add_attribute("denom", &state.denom)
+        .add_attribute("denom", &denom.denom)
         .add_attribute("sender", info.sender)
         .add_attribute("recipient", recipient)
         .add_attribute("from_member_id", &from_member.id)
@@ -321,16 +854,19 @@ fn try_transfer(
 
 // Increase the reserve supply of a member.
 // If an address is provided, mint tokens and withdraw there.
+// If a reference is provided, it can only be minted once by the member.
 fn try_mint(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     amount: Uint128,
     address: Option<String>,
+    denom: Option<String>,
+    reference: Option<String>,
 ) -> Result<Response, ContractError> {
     // Ensure no funds were sent
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during mint"));
+        return Err(ContractError::FundsNotAllowed {});
     }
 
     // Ensure amount is non-zero.
@@ -338,81 +874,363 @@ fn try_mint(
         return Err(contract_err("invalid mint amount"));
     }
 
+    // Read state
+    let state = config_read(deps.storage).load()?;
+    let denom = load_active_denom(deps.as_ref(), &state, denom, PauseScope::Mint)?;
+
     // Load membership for message sender.
     let key = info.sender.as_bytes();
-    let member = members(deps.storage).load(key)?;
-
-    // Ensure member has a kyc attribute set.
-    if member.kyc_attrs.is_empty() {
-        return Err(contract_err("member is missing kyc attribute"));
+    let member = match members_read(deps.storage).may_load(key)? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: info.sender.to_string(),
+            })
+        }
+    };
+
+    // Ensure member has a kyc attribute set.
+    if member.kyc_attrs.is_empty() {
+        return Err(ContractError::MissingKycAttr {
+            addr: member.id.to_string(),
+        });
+    }
+
+    // Ensure member is not being removed.
+    if member.removal_height.is_some() {
+        return Err(ContractError::MemberRemoving {
+            id: member.id.to_string(),
+        });
+    }
+
+    // Ensure the deposit reference was not already minted or requested.
+    if let Some(reference) = &reference {
+        if reference.trim().is_empty() {
+            return Err(contract_err("invalid mint reference"));
+        }
+        if mint_references_read(deps.storage, &member.id)
+            .may_load(reference.as_bytes())?
+            .is_some()
+        {
+            return Err(ContractError::DuplicateMintReference {
+                reference: reference.clone(),
+            });
+        }
+
+        // A pending mint holds its reference until its approval window passes.
+        if let Some(id) =
+            pending_mint_references_read(deps.storage, &member.id).may_load(reference.as_bytes())?
+        {
+            let pending_mint = pending_mints_read(deps.storage).load(&id.u128().to_be_bytes())?;
+            if Uint128::from(env.block.height) <= pending_mint.expires {
+                return Err(ContractError::DuplicateMintReference {
+                    reference: reference.clone(),
+                });
+            }
+            remove_pending_mint(deps.storage, &pending_mint);
+        }
+    }
+
+    // Withdraw to the provided address or fallback to the member account.
+    let withdraw_address = match address {
+        None => info.sender.clone(),
+        Some(addr) => {
+            // When withdrawing tokens to a non-member account, ensure the recipient has the
+            // required kyc attribute for member.
+            let address = deps.api.addr_validate(&addr)?;
+            if address != info.sender {
+                ensure_member_kyc(deps.as_ref(), &address, &member)?;
+            }
+            address
+        }
+    };
+    if is_frozen(deps.storage, &withdraw_address)? {
+        return Err(ContractError::AddressFrozen {
+            addr: withdraw_address.to_string(),
+        });
+    }
+
+    // Mints above the member's approval threshold wait for a second approval.
+    let approval = mint_approval_configs_read(deps.storage).may_load(member.id.as_bytes())?;
+    if let Some(approval) = approval.filter(|approval| amount > approval.threshold) {
+        // Fail early if the mint could never be approved.
+        next_member_supply(deps.as_ref(), &state, &member, &denom.denom, amount)?;
+
+        let id = pending_mint_seq_read(deps.storage)
+            .may_load()?
+            .unwrap_or_default()
+            + Uint128::one();
+        pending_mint_seq(deps.storage).save(&id)?;
+
+        let height = Uint128::from(env.block.height);
+        let pending_mint = PendingMint {
+            id,
+            member: member.id.clone(),
+            amount,
+            denom: denom.denom.clone(),
+            address: withdraw_address,
+            reference,
+            created: height,
+            expires: height
+                .checked_add(approval.window_blocks)
+                .map_err(StdError::from)?,
+        };
+        pending_mints(deps.storage).save(&id.u128().to_be_bytes(), &pending_mint)?;
+        if let Some(reference) = &pending_mint.reference {
+            pending_mint_references(deps.storage, &member.id).save(reference.as_bytes(), &id)?;
+        }
+
+        return Ok(Response::new()
+            .add_attribute("action", "request_mint")
+            .add_attribute("pending_mint_id", id)
+            .add_attribute("member_id", &member.id)
+            .add_attribute("amount", amount)
+            .add_attribute("denom", &denom.denom)
+            .add_attribute("withdraw_address", &pending_mint.address)
+            .add_attribute("expires", pending_mint.expires));
     }
 
+    issue_mint(
+        deps,
+        &env,
+        &state,
+        member,
+        &denom,
+        amount,
+        withdraw_address,
+        reference,
+        "mint",
+    )
+}
+
+// Mint token, withdraw it to an account and update the member's outstanding supply.
+#[allow(clippy::too_many_arguments)]
+fn issue_mint(
+    mut deps: DepsMut,
+    env: &Env,
+    state: &StateV2,
+    mut member: MemberV2,
+    denom: &Denom,
+    amount: Uint128,
+    withdraw_address: Addr,
+    reference: Option<String>,
+    action: &str,
+) -> Result<Response, ContractError> {
+    // Update the member's outstanding supply and save
+    let supply = next_member_supply(deps.as_ref(), state, &member, &denom.denom, amount)?;
+    save_member_supply(deps.branch(), state, &mut member, &denom.denom, supply)?;
+
     // Mint token.
-    let state = config_read(deps.storage).load()?;
     let mut res = Response::new()
         .add_message(MsgMintRequest {
             amount: Some(Coin {
-                denom: state.denom.clone(),
+                denom: denom.denom.clone(),
                 amount: amount.to_string(),
             }),
             administrator: env.contract.address.to_string(),
         })
         // Add wasm event attributes
-        .add_attribute("action", "mint")
+        .add_attribute("action", action)
         .add_attribute("member_id", &member.id)
         .add_attribute("amount", amount)
-        .add_attribute("denom", &state.denom);
+        .add_attribute("denom", &denom.denom);
+
+    // Record the deposit reference as minted.
+    if let Some(reference) = reference {
+        mint_references(deps.storage, &member.id).save(
+            reference.as_bytes(),
+            &MintRecord {
+                reference: reference.clone(),
+                member: member.id.clone(),
+                amount,
+                denom: denom.denom.clone(),
+                address: withdraw_address.clone(),
+                height: env.block.height.into(),
+            },
+        )?;
+        res = res.add_attribute("reference", reference);
+    }
+    res = res
+        .add_message(MsgWithdrawRequest {
+            denom: denom.denom.clone(),
+            administrator: env.contract.address.to_string(),
+            to_address: withdraw_address.to_string(),
+            amount: vec![Coin {
+                denom: denom.denom.clone(),
+                amount: amount.to_string(),
+            }],
+        })
+        .add_event(Event::from(CoinMovement {
+            from_address: env.contract.address.clone(),
+            from_address_bank_uuid: None,
+            to_address: withdraw_address.clone(),
+            to_address_bank_uuid: Some(member.id.to_string()),
+            block_height: env.block.height.into(),
+            timestamp: iso8601(env.block.time),
+            amount,
+            denom: denom.denom.clone(),
+            movement_type: CoinMovementType::Mint,
+        }))
+        .add_attribute("withdraw_address", withdraw_address);
+    Ok(res)
+}
+
+// Approve a pending mint as the member's approver or admin.
+fn try_approve_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: Uint128,
+) -> Result<Response, ContractError> {
+    // Ensure no funds were sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
 
-    // Withdraw to address or fallback.
-    match address {
+    let state = config_read(deps.storage).load()?;
+    let pending_mint = load_pending_mint(deps.as_ref(), &state, &info, id)?;
+
+    // Ensure the approval window has not passed.
+    if Uint128::from(env.block.height) > pending_mint.expires {
+        return Err(ContractError::PendingMintExpired { id });
+    }
+
+    // Ensure the mint is still allowed.
+    let denom = load_active_denom(
+        deps.as_ref(),
+        &state,
+        Some(pending_mint.denom.clone()),
+        PauseScope::Mint,
+    )?;
+    let member = match members_read(deps.storage).may_load(pending_mint.member.as_bytes())? {
+        Some(member) => member,
         None => {
-            // Withdraw tokens to the member account.
-            res = res
-                .add_message(MsgWithdrawRequest {
-                    denom: state.denom.clone(),
-                    administrator: env.contract.address.to_string(),
-                    to_address: info.sender.to_string(),
-                    amount: vec![Coin {
-                        denom: state.denom.clone(),
-                        amount: amount.to_string(),
-                    }],
-                })
-                .add_attribute("withdraw_address", info.sender)
-        }
-        Some(addr) => {
-            // When withdrawing tokens to a non-member account, ensure the recipient has the
-            // required kyc attribute for member.
-            let address = deps.api.addr_validate(&addr)?;
-            if address != info.sender {
-                matched_member(deps.as_ref(), address.clone(), vec![member])?;
-            }
-            // Withdraw minted tokens to the provided account.
-            res = res
-                .add_message(MsgWithdrawRequest {
-                    denom: state.denom.clone(),
-                    administrator: env.contract.address.to_string(),
-                    to_address: address.to_string(),
-                    amount: vec![Coin {
-                        denom: state.denom.clone(),
-                        amount: amount.to_string(),
-                    }],
-                })
-                .add_attribute("withdraw_address", address)
+            return Err(ContractError::MemberNotFound {
+                id: pending_mint.member.to_string(),
+            })
         }
     };
-    Ok(res)
+    if member.removal_height.is_some() {
+        return Err(ContractError::MemberRemoving {
+            id: member.id.to_string(),
+        });
+    }
+    if is_frozen(deps.storage, &pending_mint.address)? {
+        return Err(ContractError::AddressFrozen {
+            addr: pending_mint.address.to_string(),
+        });
+    }
+
+    remove_pending_mint(deps.storage, &pending_mint);
+
+    let res = issue_mint(
+        deps,
+        &env,
+        &state,
+        member,
+        &denom,
+        pending_mint.amount,
+        pending_mint.address,
+        pending_mint.reference,
+        "mint",
+    )?;
+    Ok(res
+        .add_attribute("pending_mint_id", id)
+        .add_attribute("approver", info.sender))
+}
+
+// Reject a pending mint as the member's approver or admin.
+fn try_reject_mint(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: Uint128,
+) -> Result<Response, ContractError> {
+    // Ensure no funds were sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let state = config_read(deps.storage).load()?;
+    let pending_mint = load_pending_mint(deps.as_ref(), &state, &info, id)?;
+    remove_pending_mint(deps.storage, &pending_mint);
+
+    Ok(Response::new()
+        .add_attribute("action", "reject_mint")
+        .add_attribute("pending_mint_id", id)
+        .add_attribute("member_id", &pending_mint.member)
+        .add_attribute("approver", info.sender))
+}
+
+// Remove a pending mint whose approval window has passed, freeing its deposit reference.
+fn try_expire_pending_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: Uint128,
+) -> Result<Response, ContractError> {
+    // Ensure no funds were sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let pending_mint = match pending_mints_read(deps.storage).may_load(&id.u128().to_be_bytes())? {
+        Some(pending_mint) => pending_mint,
+        None => return Err(ContractError::PendingMintNotFound { id }),
+    };
+
+    // Ensure the approval window has passed.
+    if Uint128::from(env.block.height) <= pending_mint.expires {
+        return Err(contract_err("pending mint has not expired"));
+    }
+    remove_pending_mint(deps.storage, &pending_mint);
+
+    Ok(Response::new()
+        .add_attribute("action", "expire_pending_mint")
+        .add_attribute("pending_mint_id", id)
+        .add_attribute("member_id", &pending_mint.member))
+}
+
+// Load a pending mint, ensuring the message sender can approve it. The member that requested
+// the mint can never approve it.
+fn load_pending_mint(
+    deps: Deps,
+    state: &StateV2,
+    info: &MessageInfo,
+    id: Uint128,
+) -> Result<PendingMint, ContractError> {
+    let pending_mint = match pending_mints_read(deps.storage).may_load(&id.u128().to_be_bytes())? {
+        Some(pending_mint) => pending_mint,
+        None => return Err(ContractError::PendingMintNotFound { id }),
+    };
+    let approver = mint_approval_configs_read(deps.storage)
+        .may_load(pending_mint.member.as_bytes())?
+        .map(|approval| approval.approver);
+    if info.sender == pending_mint.member
+        || (info.sender != state.admin && Some(&info.sender) != approver.as_ref())
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(pending_mint)
+}
+
+fn remove_pending_mint(storage: &mut dyn Storage, pending_mint: &PendingMint) {
+    pending_mints(storage).remove(&pending_mint.id.u128().to_be_bytes());
+    if let Some(reference) = &pending_mint.reference {
+        pending_mint_references(storage, &pending_mint.member).remove(reference.as_bytes());
+    }
 }
 
 // Decrease reserve token supply.
 fn try_burn(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     amount: Uint128,
+    denom: Option<String>,
 ) -> Result<Response, ContractError> {
     // Validate params.
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during burn"));
+        return Err(ContractError::FundsNotAllowed {});
     }
 
     // Ensure amount is non-zero.
@@ -422,28 +1240,52 @@ fn try_burn(
 
     // Load membership for message sender.
     let key = info.sender.as_bytes();
-    let member = members(deps.storage).load(key)?;
+    let mut member = match members(deps.storage).may_load(key)? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: info.sender.to_string(),
+            })
+        }
+    };
 
     // Read state
     let state = config_read(deps.storage).load()?;
+    let denom = load_active_denom(deps.as_ref(), &state, denom, PauseScope::Burn)?;
 
     // Ensure the sender holds at least the indicated amount of token.
     let balance = deps
         .querier
-        .query_balance(info.sender.clone(), &state.denom)?;
+        .query_balance(info.sender.clone(), &denom.denom)?;
     if balance.amount < amount {
-        return Err(contract_err("insufficient token balance in burn"));
+        return Err(ContractError::InsufficientBalance {
+            needed: amount,
+            available: balance.amount,
+        });
+    }
+
+    // Ensure the sender is not frozen.
+    if is_frozen(deps.storage, &info.sender)? {
+        return Err(ContractError::AddressFrozen {
+            addr: info.sender.to_string(),
+        });
     }
 
+    // Update the member's outstanding supply and save. Members can burn tokens minted by other
+    // members, so the supply bottoms out at zero.
+    let supply =
+        member_supply(deps.as_ref(), &state, &member, &denom.denom)?.saturating_sub(amount);
+    save_member_supply(deps.branch(), &state, &mut member, &denom.denom, supply)?;
+
     // Get token marker
     let querier = MarkerQuerier::new(&deps.querier);
-    let marker = get_marker(state.denom.clone(), &querier)?;
+    let marker = get_marker(denom.denom.clone(), &querier)?;
 
     let res = Response::new()
         // Escrow token in the marker account for burn.
         .add_message(MsgTransferRequest {
             amount: Some(Coin {
-                denom: state.denom.clone(),
+                denom: denom.denom.clone(),
                 amount: amount.to_string(),
             }),
             administrator: env.contract.address.to_string(),
@@ -453,19 +1295,300 @@ fn try_burn(
         // Burn the token.
         .add_message(MsgBurnRequest {
             amount: Some(Coin {
-                denom: state.denom.clone(),
+                denom: denom.denom.clone(),
                 amount: amount.to_string(),
             }),
             administrator: env.contract.address.to_string(),
         })
+        .add_event(Event::from(CoinMovement {
+            from_address: env.contract.address.clone(),
+            from_address_bank_uuid: None,
+            to_address: info.sender.clone(),
+            to_address_bank_uuid: Some(member.id.to_string()),
+            block_height: env.block.height.into(),
+            timestamp: iso8601(env.block.time),
+            amount,
+            denom: denom.denom.clone(),
+            movement_type: CoinMovementType::Burn,
+        }))
         // Add wasm event attributes.
         .add_attribute("action", "burn")
         .add_attribute("member_id", &member.id)
         .add_attribute("amount", amount)
-        .add_attribute("denom", &state.denom);
+        .add_attribute("denom", &denom.denom);
+    Ok(res)
+}
+
+// Escrow a holder's token in the marker account, to be redeemed for fiat at a member.
+fn try_redeem(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    member_id: String,
+    reference: String,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    if amount.is_zero() {
+        return Err(contract_err("invalid redemption amount"));
+    }
+    if reference.trim().is_empty() {
+        return Err(contract_err("invalid redemption reference"));
+    }
+
+    // Read state
+    let state = config_read(deps.storage).load()?;
+    let denom = load_active_denom(deps.as_ref(), &state, denom, PauseScope::Burn)?;
+
+    // Load the redeeming member.
+    let address = deps.api.addr_validate(&member_id)?;
+    let member = match members_read(deps.storage).may_load(address.as_bytes())? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: address.to_string(),
+            })
+        }
+    };
+    if member.removal_height.is_some() {
+        return Err(ContractError::MemberRemoving {
+            id: member.id.to_string(),
+        });
+    }
+
+    // Ensure the holder has the member's kyc attribute.
+    if info.sender != member.id {
+        ensure_member_kyc(deps.as_ref(), &info.sender, &member)?;
+    }
+    if is_frozen(deps.storage, &info.sender)? {
+        return Err(ContractError::AddressFrozen {
+            addr: info.sender.to_string(),
+        });
+    }
+
+    // Ensure the holder holds at least the indicated amount of token.
+    let balance = deps
+        .querier
+        .query_balance(info.sender.clone(), &denom.denom)?;
+    if balance.amount < amount {
+        return Err(ContractError::InsufficientBalance {
+            needed: amount,
+            available: balance.amount,
+        });
+    }
+
+    // Ensure the reference is not already open.
+    if redemptions_read(deps.storage, &member.id)
+        .may_load(reference.as_bytes())?
+        .is_some()
+    {
+        return Err(contract_err("redemption reference is already open"));
+    }
+    redemptions(deps.storage, &member.id).save(
+        reference.as_bytes(),
+        &Redemption {
+            member: member.id.clone(),
+            reference: reference.clone(),
+            holder: info.sender.clone(),
+            amount,
+            denom: denom.denom.clone(),
+            created: env.block.height.into(),
+        },
+    )?;
+
+    // Get token marker
+    let querier = MarkerQuerier::new(&deps.querier);
+    let marker = get_marker(denom.denom.clone(), &querier)?;
+    let marker_address = Addr::unchecked(marker.base_account.unwrap().address);
+
+    let res = Response::new()
+        // Escrow token in the marker account until the redemption is settled.
+        .add_message(MsgTransferRequest {
+            amount: Some(Coin {
+                denom: denom.denom.clone(),
+                amount: amount.to_string(),
+            }),
+            administrator: env.contract.address.to_string(),
+            from_address: info.sender.to_string(),
+            to_address: marker_address.to_string(),
+        })
+        .add_event(Event::from(CoinMovement {
+            from_address: info.sender.clone(),
+            from_address_bank_uuid: Some(member.id.to_string()),
+            to_address: marker_address,
+            to_address_bank_uuid: None,
+            block_height: env.block.height.into(),
+            timestamp: iso8601(env.block.time),
+            amount,
+            denom: denom.denom.clone(),
+            movement_type: CoinMovementType::Transfer,
+        }))
+        .add_attribute("action", "redeem")
+        .add_attribute("member_id", &member.id)
+        .add_attribute("reference", reference)
+        .add_attribute("holder", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("denom", &denom.denom);
+    Ok(res)
+}
+
+// Burn the escrowed token of a redemption once the member paid out the fiat.
+fn try_settle_redemption(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    reference: String,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    // Load membership for message sender.
+    let mut member = match members_read(deps.storage).may_load(info.sender.as_bytes())? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: info.sender.to_string(),
+            })
+        }
+    };
+    let redemption = load_redemption(deps.as_ref(), &member, &reference)?;
+
+    // Read state
+    let state = config_read(deps.storage).load()?;
+    let denom = load_active_denom(
+        deps.as_ref(),
+        &state,
+        Some(redemption.denom.clone()),
+        PauseScope::Burn,
+    )?;
+
+    // Update the member's outstanding supply and save. The escrowed token may have been minted
+    // by other members, so the supply bottoms out at zero.
+    let supply = member_supply(deps.as_ref(), &state, &member, &denom.denom)?
+        .saturating_sub(redemption.amount);
+    save_member_supply(deps.branch(), &state, &mut member, &denom.denom, supply)?;
+    redemptions(deps.storage, &member.id).remove(reference.as_bytes());
+
+    // The escrowed token is burned from the marker account.
+    let querier = MarkerQuerier::new(&deps.querier);
+    let marker = get_marker(denom.denom.clone(), &querier)?;
+
+    let res = Response::new()
+        // Burn the escrowed token.
+        .add_message(MsgBurnRequest {
+            amount: Some(Coin {
+                denom: denom.denom.clone(),
+                amount: redemption.amount.to_string(),
+            }),
+            administrator: env.contract.address.to_string(),
+        })
+        .add_event(Event::from(CoinMovement {
+            from_address: env.contract.address.clone(),
+            from_address_bank_uuid: None,
+            to_address: Addr::unchecked(marker.base_account.unwrap().address),
+            to_address_bank_uuid: Some(member.id.to_string()),
+            block_height: env.block.height.into(),
+            timestamp: iso8601(env.block.time),
+            amount: redemption.amount,
+            denom: denom.denom.clone(),
+            movement_type: CoinMovementType::Burn,
+        }))
+        // Settlements are burns of the member's supply.
+        .add_attribute("action", "burn")
+        .add_attribute("member_id", &member.id)
+        .add_attribute("amount", redemption.amount)
+        .add_attribute("denom", &denom.denom)
+        .add_attribute("reference", reference)
+        .add_attribute("holder", redemption.holder);
     Ok(res)
 }
 
+// Return the escrowed token of a redemption to the holder.
+fn try_reject_redemption(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    reference: String,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    // Load membership for message sender.
+    let member = match members_read(deps.storage).may_load(info.sender.as_bytes())? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: info.sender.to_string(),
+            })
+        }
+    };
+    let redemption = load_redemption(deps.as_ref(), &member, &reference)?;
+
+    // Refunds are not subject to pauses, so escrowed token can always be returned.
+    if is_frozen(deps.storage, &redemption.holder)? {
+        return Err(ContractError::AddressFrozen {
+            addr: redemption.holder.to_string(),
+        });
+    }
+    redemptions(deps.storage, &member.id).remove(reference.as_bytes());
+
+    // Get token marker
+    let querier = MarkerQuerier::new(&deps.querier);
+    let marker = get_marker(redemption.denom.clone(), &querier)?;
+
+    let res = Response::new()
+        // Withdraw the escrowed token back to the holder.
+        .add_message(MsgWithdrawRequest {
+            denom: redemption.denom.clone(),
+            administrator: env.contract.address.to_string(),
+            to_address: redemption.holder.to_string(),
+            amount: vec![Coin {
+                denom: redemption.denom.clone(),
+                amount: redemption.amount.to_string(),
+            }],
+        })
+        .add_event(Event::from(CoinMovement {
+            from_address: Addr::unchecked(marker.base_account.unwrap().address),
+            from_address_bank_uuid: None,
+            to_address: redemption.holder.clone(),
+            to_address_bank_uuid: Some(member.id.to_string()),
+            block_height: env.block.height.into(),
+            timestamp: iso8601(env.block.time),
+            amount: redemption.amount,
+            denom: redemption.denom.clone(),
+            movement_type: CoinMovementType::Transfer,
+        }))
+        .add_attribute("action", "reject_redemption")
+        .add_attribute("member_id", &member.id)
+        .add_attribute("reference", reference)
+        .add_attribute("holder", redemption.holder)
+        .add_attribute("amount", redemption.amount)
+        .add_attribute("denom", redemption.denom);
+    Ok(res)
+}
+
+// Load an open redemption of a member.
+fn load_redemption(
+    deps: Deps,
+    member: &MemberV2,
+    reference: &str,
+) -> Result<Redemption, ContractError> {
+    match redemptions_read(deps.storage, &member.id).may_load(reference.as_bytes())? {
+        Some(redemption) => Ok(redemption),
+        None => Err(ContractError::RedemptionNotFound {
+            reference: reference.to_string(),
+        }),
+    }
+}
+
 // Add a member kyc attribute.
 fn try_add_kyc(
     deps: DepsMut,
@@ -475,12 +1598,12 @@ fn try_add_kyc(
 ) -> Result<Response, ContractError> {
     // Validate params.
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during kyc add"));
+        return Err(ContractError::FundsNotAllowed {});
     }
     if kyc_attr.trim().is_empty() {
         return Err(contract_err("kyc attribute name is empty"));
     }
-    let valid_attr = kyc_attr.trim().into();
+    let valid_attr: String = kyc_attr.trim().into();
 
     // Load state and ensure sender is the administrator or calling id.
     let state = config(deps.storage).load()?;
@@ -493,19 +1616,22 @@ fn try_add_kyc(
                 return Err(ContractError::Unauthorized {});
             }
 
-            members_read(deps.storage).load(address.as_bytes())?
+            load_member(deps.as_ref(), &address)?
         }
-        None => members_read(deps.storage).load(info.sender.as_bytes())?,
+        None => load_member(deps.as_ref(), &info.sender)?,
     };
 
-    let curr_kyc_attributes = get_attributes(deps.as_ref())?;
     // Ensure kyc attribute wasn't already added
-    if curr_kyc_attributes.contains(&valid_attr) {
-        return Err(contract_err("kyc attribute already exists"));
+    if kyc_attr_index_read(deps.storage)
+        .may_load(valid_attr.as_bytes())?
+        .is_some()
+    {
+        return Err(ContractError::DuplicateKycAttr { attr: valid_attr });
     }
     // Add the kyc attribute and save
     member.kyc_attrs.push(valid_attr.clone());
     members(deps.storage).save(member.id.as_bytes(), &member)?;
+    kyc_attr_index(deps.storage).save(valid_attr.as_bytes(), &member.id)?;
 
     // Add wasm event attributes
     Ok(Response::new()
@@ -523,12 +1649,12 @@ fn try_remove_kyc(
 ) -> Result<Response, ContractError> {
     // Validate params.
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during kyc remove"));
+        return Err(ContractError::FundsNotAllowed {});
     }
     if kyc_attr.trim().is_empty() {
         return Err(contract_err("kyc attribute name is empty"));
     }
-    let valid_attr = kyc_attr.trim().into();
+    let valid_attr: String = kyc_attr.trim().into();
 
     // Load state and ensure sender is the administrator or calling id.
     let state = config(deps.storage).load()?;
@@ -541,9 +1667,9 @@ fn try_remove_kyc(
                 return Err(ContractError::Unauthorized {});
             }
 
-            members_read(deps.storage).load(address.as_bytes())?
+            load_member(deps.as_ref(), &address)?
         }
-        None => members_read(deps.storage).load(info.sender.as_bytes())?,
+        None => load_member(deps.as_ref(), &info.sender)?,
     };
     // Ensure kyc attribute exists
     if !member.kyc_attrs.contains(&valid_attr) {
@@ -553,6 +1679,7 @@ fn try_remove_kyc(
     // Remove the kyc attribute and save
     member.kyc_attrs.retain(|kyc_attr| *kyc_attr != valid_attr);
     members(deps.storage).save(member.id.as_bytes(), &member)?;
+    kyc_attr_index(deps.storage).remove(valid_attr.as_bytes());
 
     // Add wasm event attributes
     Ok(Response::new()
@@ -561,10 +1688,17 @@ fn try_remove_kyc(
         .add_attribute("member_id", &member.id))
 }
 
-fn try_set_admin(deps: DepsMut, info: MessageInfo, id: String) -> Result<Response, ContractError> {
+// Propose a new admin, who must accept before the admin changes.
+fn try_propose_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    expires_at: Option<Uint128>,
+) -> Result<Response, ContractError> {
     // Validate params.
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during set admin"));
+        return Err(ContractError::FundsNotAllowed {});
     }
 
     let address = deps.api.addr_validate(&id)?;
@@ -580,16 +1714,94 @@ fn try_set_admin(deps: DepsMut, info: MessageInfo, id: String) -> Result<Respons
         return Err(contract_err("admin address is unchanged"));
     }
 
+    // Ensure the expiry is in the future.
+    if let Some(expires_at) = expires_at {
+        if expires_at <= Uint128::from(env.block.height) {
+            return Err(contract_err("admin proposal expiry must be in the future"));
+        }
+    }
+
+    // Replace any existing proposal and save
+    state.pending_admin = Some(PendingAdmin {
+        address,
+        expires_at,
+    });
+    config(deps.storage).save(&state)?;
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "propose_admin")
+        .add_attribute("admin", &state.admin)
+        .add_attribute("pending_admin", &id))
+}
+
+// Accept a pending admin proposal as the proposed admin.
+fn try_accept_admin(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let mut state = config_read(deps.storage).load()?;
+
+    // Ensure there is a proposal.
+    let pending_admin = match state.pending_admin {
+        Some(pending_admin) => pending_admin,
+        None => return Err(contract_err("no pending admin proposal")),
+    };
+
+    // Ensure message sender is the proposed admin.
+    if info.sender != pending_admin.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Ensure the proposal has not expired.
+    if let Some(expires_at) = pending_admin.expires_at {
+        if Uint128::from(env.block.height) > expires_at {
+            return Err(contract_err("admin proposal has expired"));
+        }
+    }
+
     // Update the admin and save
-    state.admin = address;
+    state.admin = pending_admin.address;
+    state.pending_admin = None;
     config(deps.storage).save(&state)?;
 
     // Add wasm event attributes
     Ok(Response::new()
-        .add_attribute("action", "set_admin")
+        .add_attribute("action", "accept_admin")
         .add_attribute("admin", &state.admin))
 }
 
+// Cancel a pending admin proposal.
+fn try_cancel_admin_proposal(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let mut state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Ensure there is a proposal.
+    let pending_admin = match state.pending_admin {
+        Some(pending_admin) => pending_admin,
+        None => return Err(contract_err("no pending admin proposal")),
+    };
+
+    state.pending_admin = None;
+    config(deps.storage).save(&state)?;
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "cancel_admin_proposal")
+        .add_attribute("pending_admin", &pending_admin.address))
+}
+
 fn try_add_executor(
     deps: DepsMut,
     info: MessageInfo,
@@ -597,7 +1809,7 @@ fn try_add_executor(
 ) -> Result<Response, ContractError> {
     // Validate params.
     if !info.funds.is_empty() {
-        return Err(contract_err("no funds should be sent during add executor"));
+        return Err(ContractError::FundsNotAllowed {});
     }
 
     let address = deps.api.addr_validate(&id)?.into_string();
@@ -617,7 +1829,11 @@ fn try_add_executor(
         .add_attribute("action", "add_executor")
         .add_attribute("executor", &address);
 
-    // Add the executor and save
+    // Add the executor without spending limits and save
+    executor_configs(deps.storage).save(
+        address.as_bytes(),
+        &ExecutorConfig::unlimited(Addr::unchecked(&address)),
+    )?;
     state.executors.push(address);
     config(deps.storage).save(&state)?;
 
@@ -632,9 +1848,7 @@ fn try_remove_executor(
 ) -> Result<Response, ContractError> {
     // Validate params.
     if !info.funds.is_empty() {
-        return Err(contract_err(
-            "no funds should be sent during remove executor",
-        ));
+        return Err(ContractError::FundsNotAllowed {});
     }
 
     let address = deps.api.addr_validate(&id)?.into_string();
@@ -651,6 +1865,7 @@ fn try_remove_executor(
     }
 
     // Remove the executor and save
+    executor_configs(deps.storage).remove(address.as_bytes());
     state.executors.retain(|executor| *executor != address);
     config(deps.storage).save(&state)?;
 
@@ -662,6 +1877,7 @@ fn try_remove_executor(
 
 // Transfer token from sender to recipient with sender specified by executor.
 // Both accounts must either be member accounts, or have the required kyc attributes.
+#[allow(clippy::too_many_arguments)]
 fn try_executor_transfer(
     deps: DepsMut,
     env: Env,
@@ -669,18 +1885,129 @@ fn try_executor_transfer(
     amount: Uint128,
     sender: String,
     recipient: String,
+    authorization: Option<TransferAuthorization>,
+    denom: Option<String>,
 ) -> Result<Response, ContractError> {
     // Read state
     let state = config_read(deps.storage).load()?;
+    let executor = info.sender;
 
     // Ensure sender is a valid executor
-    if !state.executors.contains(&info.sender.into_string()) {
+    if !state.executors.contains(&executor.to_string()) {
         return Err(ContractError::Unauthorized {});
     }
+    let denom = load_active_denom(deps.as_ref(), &state, denom, PauseScope::ExecutorTransfer)?;
+    let allowance_denom = allowance_denom(&state, &denom);
 
     // Validate sender address
     let sender = deps.api.addr_validate(&sender)?;
 
+    // Ensure the transfer is within the executor spending limits.
+    let executor_config = executor_configs_read(deps.storage)
+        .may_load(executor.as_bytes())?
+        .unwrap_or_else(|| ExecutorConfig::unlimited(executor.clone()));
+    if let SenderScope::Members { ids } = &executor_config.sender_scope {
+        let sender_member = match members_read(deps.storage).may_load(sender.as_bytes())? {
+            Some(m) => m,
+            None => matched_member(deps.as_ref(), &sender)?,
+        };
+        if !ids.contains(&sender_member.id) {
+            return Err(contract_err("sender is outside executor scope"));
+        }
+    }
+    if let Some(max_per_tx) = executor_config.max_per_tx {
+        if amount > max_per_tx {
+            return Err(ContractError::ExecutorMaxPerTxExceeded {
+                max: max_per_tx,
+                amount,
+            });
+        }
+    }
+
+    if let Some(window_cap) = executor_config.window_cap {
+        // The rolling window holds the last window_blocks blocks, up to the current one.
+        let height = Uint128::from(env.block.height);
+        let window_start = (height + Uint128::one()).saturating_sub(executor_config.window_blocks);
+        let window_start_key = window_start.u128().to_be_bytes();
+
+        // Prune the spends that left the window.
+        let expired = executor_spends_read(deps.storage, &executor)
+            .range(None, Some(&window_start_key), Order::Ascending)
+            .map(|item| item.map(|(key, _)| key))
+            .collect::<StdResult<Vec<Vec<u8>>>>()?;
+        for key in expired {
+            executor_spends(deps.storage, &executor).remove(&key);
+        }
+
+        // Ensure the spends within the window stay under the cap.
+        let mut window_spent = amount;
+        for item in executor_spends_read(deps.storage, &executor).range(
+            Some(&window_start_key),
+            None,
+            Order::Ascending,
+        ) {
+            let (_, spent) = item?;
+            window_spent = window_spent.checked_add(spent).map_err(StdError::from)?;
+        }
+        if window_spent > window_cap {
+            return Err(ContractError::ExecutorWindowCapExceeded {
+                cap: window_cap,
+                spent: window_spent,
+            });
+        }
+
+        let height_key = height.u128().to_be_bytes();
+        let block_spent = executor_spends_read(deps.storage, &executor)
+            .may_load(&height_key)?
+            .unwrap_or_default()
+            + amount;
+        executor_spends(deps.storage, &executor).save(&height_key, &block_spent)?;
+    }
+
+    // Ensure the sender consented to the transfer, either by a signed authorization or by the
+    // allowance it granted the executor.
+    match authorization {
+        Some(authorization) => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            let payload = AuthorizationPayload {
+                contract: env.contract.address.clone(),
+                executor,
+                sender: sender.clone(),
+                recipient,
+                amount,
+                nonce: authorization.nonce,
+                expires: authorization.expires,
+                denom: allowance_denom.map(String::from),
+            };
+            verify_authorization(deps.as_ref(), &env, &payload, &authorization)?;
+            authorization_nonces(deps.storage).save(sender.as_bytes(), &authorization.nonce)?;
+        }
+        None => {
+            let available = allowances_read(deps.storage, &sender, allowance_denom)
+                .may_load(executor.as_bytes())?
+                .map_or(Uint128::zero(), |allowance| allowance.amount);
+            if available < amount {
+                return Err(ContractError::InsufficientAllowance {
+                    needed: amount,
+                    available,
+                });
+            }
+            let remaining = available - amount;
+            if remaining.is_zero() {
+                allowances(deps.storage, &sender, allowance_denom).remove(executor.as_bytes());
+            } else {
+                allowances(deps.storage, &sender, allowance_denom).save(
+                    executor.as_bytes(),
+                    &Allowance {
+                        holder: sender.clone(),
+                        executor: executor.clone(),
+                        amount: remaining,
+                    },
+                )?;
+            }
+        }
+    }
+
     try_transfer(
         deps,
         env,
@@ -690,186 +2017,5464 @@ fn try_executor_transfer(
         },
         amount,
         recipient,
+        Some(denom.denom),
     )
 }
 
-// A helper function for creating generic contract errors.
-fn contract_err(s: &str) -> ContractError {
-    ContractError::Std(StdError::generic_err(s))
-}
-
-// Return the first matched attribute, otherwise return an error.
-fn matched_member(
+// Verify a holder's signed authorization of an executor transfer.
+fn verify_authorization(
     deps: Deps,
-    addr: Addr,
-    members: Vec<MemberV2>,
-) -> Result<MemberV2, ContractError> {
-    // Skip the check if no attributes are required.
-    if members.is_empty() {
-        return Err(contract_err("requires at least one member"));
-    }
-    // Check for all provided attributes
-    let querier = AttributeQuerier::new(&deps.querier);
-    for member in members.iter() {
-        for kyc_attr in member.kyc_attrs.iter() {
-            let res = querier.attribute(addr.to_string(), kyc_attr.to_string(), None)?;
-            if !res.attributes.is_empty() {
-                return Ok(member.clone());
-            }
+    env: &Env,
+    payload: &AuthorizationPayload,
+    authorization: &TransferAuthorization,
+) -> Result<(), ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidAuthorization {
+        reason: reason.into(),
+    };
+
+    // Ensure the authorization is current and has not been used.
+    if Uint128::from(env.block.height) > authorization.expires {
+        return Err(invalid("authorization expired"));
+    }
+    if let Some(last_nonce) =
+        authorization_nonces_read(deps.storage).may_load(payload.sender.as_bytes())?
+    {
+        if authorization.nonce <= last_nonce {
+            return Err(invalid("nonce already used"));
         }
     }
-    return Err(contract_err(&format!(
-        "no kyc attributes found for {}",
-        addr
-    )));
-}
 
-/// Query contract state
-#[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<QueryResponse, ContractError> {
-    match msg {
-        QueryMsg::GetMembers {} => try_get_members(deps),
-        QueryMsg::GetMember { id } => try_get_member(deps, id),
-        QueryMsg::GetContractInfo {} => try_get_contract_info(deps),
-        QueryMsg::GetVersionInfo {} => try_get_version_info(deps),
+    // Ensure the public key belongs to the sender.
+    let (hrp, _, _) =
+        bech32::decode(payload.sender.as_str()).map_err(|_| invalid("invalid sender address"))?;
+    if pub_key_address(&authorization.pub_key, &hrp)? != payload.sender.as_str() {
+        return Err(invalid("public key does not match sender"));
     }
-}
 
-// Query all members.
-fn try_get_members(deps: Deps) -> Result<QueryResponse, ContractError> {
-    Ok(to_binary(&Members {
-        members: get_members(deps)?,
-    })?)
+    // Ensure the sender signed the payload.
+    let hash = Sha256::digest(to_vec(payload)?);
+    let verified = deps
+        .api
+        .secp256k1_verify(&hash, &authorization.signature, &authorization.pub_key)
+        .map_err(|_| invalid("invalid signature"))?;
+    if !verified {
+        return Err(invalid("invalid signature"));
+    }
+    Ok(())
 }
 
-// Query member by ID.
-fn try_get_member(deps: Deps, id: String) -> Result<QueryResponse, ContractError> {
+// Set the spending limits of an executor.
+fn try_set_executor_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+    sender_scope: SenderScope,
+    max_per_tx: Option<Uint128>,
+    window_blocks: Uint128,
+    window_cap: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    if window_cap.is_some() && window_blocks.is_zero() {
+        return Err(contract_err("window blocks are required for a window cap"));
+    }
+
     let address = deps.api.addr_validate(&id)?;
-    let key = address.as_bytes();
-    let member = members_read(deps.storage).load(key)?;
-    let bin = to_binary(&member)?;
-    Ok(bin)
-}
+    let state = config_read(deps.storage).load()?;
 
-// Query contract state
-fn try_get_contract_info(deps: Deps) -> Result<QueryResponse, ContractError> {
-    let state = &config_read(deps.storage).load()?;
-    let bin = to_binary(state)?;
-    Ok(bin)
-}
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
 
-// Get contract version
-fn try_get_version_info(deps: Deps) -> Result<QueryResponse, ContractError> {
-    let version = &cw2::get_contract_version(deps.storage)?;
-    let bin = to_binary(version)?;
-    Ok(bin)
-}
+    // Ensure executor exists
+    if !state.executors.contains(&address.to_string()) {
+        return Err(contract_err("executor does not exist"));
+    }
 
-// Read all members from bucket storage.
-fn get_members(deps: Deps) -> Result<Vec<MemberV2>, ContractError> {
-    members_read(deps.storage)
-        .range(None, None, Order::Ascending)
-        .map(|item| {
-            let (_, member) = item?;
-            Ok(member)
-        })
-        .collect()
-}
+    // Update the limits and save, keeping the recorded spends
+    let mut executor_config = executor_configs_read(deps.storage)
+        .may_load(address.as_bytes())?
+        .unwrap_or_else(|| ExecutorConfig::unlimited(address.clone()));
+    executor_config.sender_scope = sender_scope;
+    executor_config.max_per_tx = max_per_tx;
+    executor_config.window_blocks = window_blocks;
+    executor_config.window_cap = window_cap;
+    executor_configs(deps.storage).save(address.as_bytes(), &executor_config)?;
 
-// Get all kyc attributes for members.
-fn get_attributes(deps: Deps) -> Result<Vec<String>, ContractError> {
-    Ok(get_members(deps)?
-        .into_iter()
-        .flat_map(|item| item.kyc_attrs)
-        .collect())
+    // Add wasm event attributes
+    let mut res = Response::new()
+        .add_attribute("action", "set_executor_config")
+        .add_attribute("executor", address)
+        .add_attribute("window_blocks", window_blocks);
+    if let Some(max_per_tx) = max_per_tx {
+        res = res.add_attribute("max_per_tx", max_per_tx);
+    }
+    if let Some(window_cap) = window_cap {
+        res = res.add_attribute("window_cap", window_cap);
+    }
+    Ok(res)
 }
 
-fn get_marker(id: String, querier: &MarkerQuerier<Empty>) -> StdResult<MarkerAccount> {
-    let response = querier.marker(id)?;
-    if let Some(marker) = response.marker {
-        return if let Ok(account) = MarkerAccount::try_from(marker) {
-            Ok(account)
-        } else {
-            Err(StdError::generic_err("unable to type-cast marker account"))
-        };
-    } else {
-        Err(StdError::generic_err("no marker found for id"))
+// Increase the amount an executor can transfer from the sender.
+fn try_increase_allowance(
+    deps: DepsMut,
+    info: MessageInfo,
+    executor: String,
+    amount: Uint128,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    if amount.is_zero() {
+        return Err(contract_err("invalid allowance amount"));
+    }
+
+    let executor = deps.api.addr_validate(&executor)?;
+    let state = config_read(deps.storage).load()?;
+    let denom = load_denom(deps.as_ref(), &state, denom)?;
+
+    // Ensure executor exists
+    if !state.executors.contains(&executor.to_string()) {
+        return Err(contract_err("executor does not exist"));
     }
+
+    let mut allowances = allowances(deps.storage, &info.sender, allowance_denom(&state, &denom));
+    let mut allowance = allowances
+        .may_load(executor.as_bytes())?
+        .unwrap_or(Allowance {
+            holder: info.sender.clone(),
+            executor: executor.clone(),
+            amount: Uint128::zero(),
+        });
+    allowance.amount = allowance
+        .amount
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    allowances.save(executor.as_bytes(), &allowance)?;
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "increase_allowance")
+        .add_attribute("holder", &allowance.holder)
+        .add_attribute("executor", &allowance.executor)
+        .add_attribute("amount", allowance.amount)
+        .add_attribute("denom", &denom.denom))
 }
 
-/// Called when migrating a contract instance to a new code ID.
-#[entry_point]
-pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
-    let ver_result = get_contract_version(deps.storage);
-    let ver = match ver_result {
-        Ok(ver) => ver,
-        // Default if running older version never set
-        _ => ContractVersion {
-            contract: CONTRACT_NAME.into(),
-            version: "0.0.1".into(),
-        },
+// Decrease the amount an executor can transfer from the sender.
+fn try_decrease_allowance(
+    deps: DepsMut,
+    info: MessageInfo,
+    executor: String,
+    amount: Uint128,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    if amount.is_zero() {
+        return Err(contract_err("invalid allowance amount"));
+    }
+
+    let executor = deps.api.addr_validate(&executor)?;
+    let state = config_read(deps.storage).load()?;
+    let denom = load_denom(deps.as_ref(), &state, denom)?;
+
+    let mut allowances = allowances(deps.storage, &info.sender, allowance_denom(&state, &denom));
+    let mut allowance = match allowances.may_load(executor.as_bytes())? {
+        Some(allowance) => allowance,
+        None => return Err(contract_err("allowance does not exist")),
     };
 
-    // Sanity check contract name for match.
-    if ver.contract != CONTRACT_NAME {
-        return Err(contract_err("can only upgrade from same type"));
+    // Revoke the allowance once it reaches zero
+    allowance.amount = allowance.amount.saturating_sub(amount);
+    if allowance.amount.is_zero() {
+        allowances.remove(executor.as_bytes());
+    } else {
+        allowances.save(executor.as_bytes(), &allowance)?;
     }
 
-    // Ensure we are upgrading to a newer version only.
-    let current_version = Version::parse(&ver.version)?;
-    let new_version = Version::parse(CONTRACT_VERSION)?;
-    if current_version >= new_version {
-        return Err(contract_err("cannot upgrade from same or newer version"));
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "decrease_allowance")
+        .add_attribute("holder", &allowance.holder)
+        .add_attribute("executor", &allowance.executor)
+        .add_attribute("amount", allowance.amount)
+        .add_attribute("denom", &denom.denom))
+}
+
+// Set the max outstanding supply of a denom a member can mint.
+fn try_set_member_limit(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+    max_supply: Option<Uint128>,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
     }
 
-    // migrate state
-    migrate_state(deps.branch(), current_version.clone(), &msg)?;
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
 
-    // migrate join proposals
-    migrate_join_proposals(deps.branch(), current_version.clone(), &msg)?;
+    // Read state
+    let state = config_read(deps.storage).load()?;
 
-    // migrate members
-    migrate_members(deps.branch(), current_version, &msg)?;
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
 
-    // lastly, migrate version
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let mut member = match members_read(deps.storage).may_load(key)? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: address.to_string(),
+            })
+        }
+    };
+    let denom = load_denom(deps.as_ref(), &state, denom)?;
 
-    Ok(Response::default())
+    // Update the max supply and save
+    if denom.denom == state.denom {
+        member.max_supply = max_supply;
+        members(deps.storage).save(key, &member)?;
+    } else {
+        match max_supply {
+            Some(max_supply) => {
+                denom_max_supplies(deps.storage, &denom.denom).save(key, &max_supply)?
+            }
+            None => denom_max_supplies(deps.storage, &denom.denom).remove(key),
+        }
+    }
+    let supply = member_supply(deps.as_ref(), &state, &member, &denom.denom)?;
+
+    // Add wasm event attributes
+    let mut res = Response::new()
+        .add_attribute("action", "set_member_limit")
+        .add_attribute("member_id", &member.id)
+        .add_attribute("supply", supply)
+        .add_attribute("denom", &denom.denom);
+    if let Some(max_supply) = max_supply {
+        res = res.add_attribute("max_supply", max_supply);
+    }
+    Ok(res)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[allow(deprecated)]
-    use crate::join_proposal::{legacy_join_proposals, JoinProposal};
-    #[allow(deprecated)]
-    use crate::member::{legacy_members, Member};
-    #[allow(deprecated)]
-    use crate::msg::VoteChoice;
-    #[allow(deprecated)]
-    use crate::state::{legacy_config, State};
-    use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{coin, Binary, CosmosMsg, Decimal};
-    use prost::Message;
-    use provwasm_mocks::mock_provenance_dependencies;
-    use provwasm_std::shim::Any;
-    use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
-    use provwasm_std::types::provenance::attribute::v1::{
-        Attribute, AttributeType, QueryAttributeRequest, QueryAttributeResponse,
+// Set the threshold above which a member's mints require approval.
+fn try_set_mint_approval(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+    threshold: Option<Uint128>,
+    approver: Option<String>,
+    window_blocks: Uint128,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+
+    // Read state
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Ensure the member exists.
+    if members_read(deps.storage).may_load(key)?.is_none() {
+        return Err(ContractError::MemberNotFound {
+            id: address.to_string(),
+        });
+    }
+
+    let res = Response::new()
+        .add_attribute("action", "set_mint_approval")
+        .add_attribute("member_id", &address);
+
+    // Without a threshold, the member's mints don't require approval.
+    let threshold = match threshold {
+        Some(threshold) => threshold,
+        None => {
+            mint_approval_configs(deps.storage).remove(key);
+            return Ok(res);
+        }
     };
-    use provwasm_std::types::provenance::marker::v1::{
-        MarkerStatus, QueryMarkerRequest, QueryMarkerResponse,
+
+    // Validate the approver and approval window.
+    let approver = match approver {
+        Some(approver) => deps.api.addr_validate(&approver)?,
+        None => return Err(contract_err("mint approver is required")),
     };
-    use std::convert::TryInto;
+    if approver == address {
+        return Err(contract_err("mint approver cannot be the member"));
+    }
+    if window_blocks.is_zero() {
+        return Err(contract_err("invalid mint approval window"));
+    }
+
+    mint_approval_configs(deps.storage).save(
+        key,
+        &MintApprovalConfig {
+            member: address.clone(),
+            threshold,
+            approver: approver.clone(),
+            window_blocks,
+        },
+    )?;
+
+    Ok(res
+        .add_attribute("threshold", threshold)
+        .add_attribute("approver", approver)
+        .add_attribute("window_blocks", window_blocks))
+}
+
+// Set the number of blocks between member removal and finalized removal.
+fn try_set_removal_delay(
+    deps: DepsMut,
+    info: MessageInfo,
+    blocks: Uint128,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let mut state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Update the removal delay and save
+    state.removal_delay = blocks;
+    config(deps.storage).save(&state)?;
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "set_removal_delay")
+        .add_attribute("removal_delay", blocks))
+}
+
+// Set the voting weight of a member.
+fn try_set_member_weight(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+    weight: Uint128,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+
+    // Read state
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut members = members(deps.storage);
+    let mut member = match members.may_load(key)? {
+        Some(member) => member,
+        None => {
+            return Err(ContractError::MemberNotFound {
+                id: address.to_string(),
+            })
+        }
+    };
+
+    // Update the weight and save
+    member.weight = weight;
+    members.save(key, &member)?;
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "set_member_weight")
+        .add_attribute("member_id", &member.id)
+        .add_attribute("weight", weight))
+}
+
+// Set the join proposal quorum percentage and voting window.
+fn try_set_proposal_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    quorum_pct: Decimal,
+    vote_duration: Uint128,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    if quorum_pct.is_zero() || quorum_pct > Decimal::one() {
+        return Err(contract_err("invalid quorum percentage"));
+    }
+    if vote_duration.is_zero() {
+        return Err(contract_err("invalid vote duration"));
+    }
+
+    let mut state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Update the proposal config and save
+    state.quorum_pct = quorum_pct;
+    state.vote_duration = vote_duration;
+    config(deps.storage).save(&state)?;
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "set_proposal_config")
+        .add_attribute("quorum_pct", quorum_pct.to_string())
+        .add_attribute("vote_duration", vote_duration))
+}
+
+// Halt a class of operations.
+fn try_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    scope: PauseScope,
+    reason: String,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    if reason.trim().is_empty() {
+        return Err(contract_err("pause reason is empty"));
+    }
+
+    let mut state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Replace the reason if the scope is already paused
+    let paused_scope = PausedScope {
+        scope: scope.clone(),
+        reason: reason.clone(),
+    };
+    let mut res = Response::new()
+        .add_attribute("action", "pause")
+        .add_attribute("scope", format!("{:?}", scope))
+        .add_attribute("reason", reason);
+    match denom {
+        None => {
+            state.paused.retain(|paused| paused.scope != scope);
+            state.paused.push(paused_scope);
+            config(deps.storage).save(&state)?;
+        }
+        Some(denom) => {
+            let mut denom = load_denom(deps.as_ref(), &state, Some(denom))?;
+            denom.paused.retain(|paused| paused.scope != scope);
+            denom.paused.push(paused_scope);
+            denoms(deps.storage).save(denom.denom.as_bytes(), &denom)?;
+            res = res.add_attribute("denom", denom.denom);
+        }
+    }
+
+    // Add wasm event attributes
+    Ok(res)
+}
+
+// Resume a paused class of operations.
+fn try_unpause(
+    deps: DepsMut,
+    info: MessageInfo,
+    scope: PauseScope,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let mut state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut res = Response::new()
+        .add_attribute("action", "unpause")
+        .add_attribute("scope", format!("{:?}", scope));
+    match denom {
+        None => {
+            // Ensure the scope is paused.
+            if !state.paused.iter().any(|paused| paused.scope == scope) {
+                return Err(contract_err("scope is not paused"));
+            }
+            state.paused.retain(|paused| paused.scope != scope);
+            config(deps.storage).save(&state)?;
+        }
+        Some(denom) => {
+            // Ensure the scope is paused for the denom.
+            let mut denom = load_denom(deps.as_ref(), &state, Some(denom))?;
+            if !denom.paused.iter().any(|paused| paused.scope == scope) {
+                return Err(contract_err("scope is not paused"));
+            }
+            denom.paused.retain(|paused| paused.scope != scope);
+            denoms(deps.storage).save(denom.denom.as_bytes(), &denom)?;
+            res = res.add_attribute("denom", denom.denom);
+        }
+    }
+
+    // Add wasm event attributes
+    Ok(res)
+}
+
+// Register a denom, creating its marker if it doesn't exist.
+fn try_add_denom(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Ensure the denom is not already registered.
+    if load_denom(deps.as_ref(), &state, Some(denom.clone())).is_ok() {
+        return Err(contract_err("denom is already registered"));
+    }
+
+    denoms(deps.storage).save(denom.as_bytes(), &Denom::enabled(denom.clone()))?;
+
+    // Create the marker and grant permissions if it doesn't exist.
+    let mut res = Response::new();
+    if !marker_exists(deps.as_ref(), &denom) {
+        // If we need to create the marker, validate denom length.
+        if denom.len() < MIN_DENOM_LEN {
+            return Err(contract_err("invalid denom length"));
+        }
+
+        res = res.add_message(create_marker_request(&env, &state.admin, &denom));
+    }
+
+    // Add wasm event attributes
+    Ok(res
+        .add_attribute("action", "add_denom")
+        .add_attribute("denom", denom))
+}
+
+// Enable or disable minting, burning and transferring a denom.
+fn try_set_denom_enabled(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Update the denom and save
+    let mut denom = load_denom(deps.as_ref(), &state, Some(denom))?;
+    denom.enabled = enabled;
+    denoms(deps.storage).save(denom.denom.as_bytes(), &denom)?;
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "set_denom_enabled")
+        .add_attribute("denom", denom.denom)
+        .add_attribute("enabled", enabled.to_string()))
+}
+
+// Place a compliance hold on an address.
+fn try_freeze_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    if reason.trim().is_empty() {
+        return Err(contract_err("freeze reason is empty"));
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Ensure the address is not already frozen.
+    if is_frozen(deps.storage, &address)? {
+        return Err(contract_err("address is already frozen"));
+    }
+
+    frozen_addresses(deps.storage).save(
+        address.as_bytes(),
+        &FrozenAddress {
+            address: address.clone(),
+            frozen: Uint128::from(env.block.height),
+            reason: reason.clone(),
+        },
+    )?;
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "freeze_address")
+        .add_attribute("address", address)
+        .add_attribute("reason", reason))
+}
+
+// Remove a compliance hold from an address.
+fn try_unfreeze_address(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: String,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+
+    let address = deps.api.addr_validate(&id)?;
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Ensure the address is frozen.
+    if !is_frozen(deps.storage, &address)? {
+        return Err(contract_err("address is not frozen"));
+    }
+
+    frozen_addresses(deps.storage).remove(address.as_bytes());
+
+    // Add wasm event attributes
+    Ok(Response::new()
+        .add_attribute("action", "unfreeze_address")
+        .add_attribute("address", address))
+}
+
+// Move dcc out of a frozen address by admin.
+#[allow(clippy::too_many_arguments)]
+fn try_force_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+    sender: String,
+    recipient: String,
+    reason: String,
+    denom: Option<String>,
+) -> Result<Response, ContractError> {
+    // Validate params.
+    if !info.funds.is_empty() {
+        return Err(ContractError::FundsNotAllowed {});
+    }
+    if amount.is_zero() {
+        return Err(contract_err("invalid transfer amount"));
+    }
+    if reason.trim().is_empty() {
+        return Err(contract_err("force transfer reason is empty"));
+    }
+
+    let sender = deps.api.addr_validate(&sender)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let state = config_read(deps.storage).load()?;
+
+    // Ensure message sender is admin.
+    if info.sender != state.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Compliance holds apply to disabled and paused denoms too.
+    let denom = load_denom(deps.as_ref(), &state, denom)?;
+
+    // Only tokens held by frozen addresses can be moved.
+    if !is_frozen(deps.storage, &sender)? {
+        return Err(contract_err("sender address is not frozen"));
+    }
+    if is_frozen(deps.storage, &recipient)? {
+        return Err(ContractError::AddressFrozen {
+            addr: recipient.to_string(),
+        });
+    }
+
+    // Ensure the frozen address holds at least the indicated amount of token.
+    let balance = deps.querier.query_balance(sender.clone(), &denom.denom)?;
+    if balance.amount < amount {
+        return Err(ContractError::InsufficientBalance {
+            needed: amount,
+            available: balance.amount,
+        });
+    }
+
+    // Ensure the recipient has the required member kyc attribute.
+    let to_member = match members_read(deps.storage).may_load(recipient.as_bytes())? {
+        Some(m) => m,
+        None => matched_member(deps.as_ref(), &recipient)?,
+    };
+
+    // Ensure the recipient member is not being removed.
+    if to_member.removal_height.is_some() {
+        return Err(contract_err("recipient member is being removed"));
+    }
+
+    // The frozen address may no longer belong to a member.
+    let from_member = match members_read(deps.storage).may_load(sender.as_bytes())? {
+        Some(m) => Some(m),
+        None => matched_member(deps.as_ref(), &sender).ok(),
+    };
+
+    // Record the force transfer.
+    let id = force_transfer_seq_read(deps.storage)
+        .may_load()?
+        .unwrap_or_default()
+        + Uint128::one();
+    force_transfer_seq(deps.storage).save(&id)?;
+    force_transfers(deps.storage, &sender).save(
+        &id.u128().to_be_bytes(),
+        &ForceTransfer {
+            id,
+            address: sender.clone(),
+            recipient: recipient.clone(),
+            amount,
+            denom: denom.denom.clone(),
+            reason: reason.clone(),
+            height: env.block.height.into(),
+        },
+    )?;
+
+    let res = Response::new()
+        .add_message(MsgTransferRequest {
+            amount: Some(Coin {
+                denom: denom.denom.clone(),
+                amount: amount.to_string(),
+            }),
+            administrator: env.contract.address.to_string(),
+            from_address: sender.to_string(),
+            to_address: recipient.to_string(),
+        })
+        .add_event(Event::from(CoinMovement {
+            from_address: sender.clone(),
+            from_address_bank_uuid: from_member.map(|m| m.id.to_string()),
+            to_address: recipient.clone(),
+            to_address_bank_uuid: Some(to_member.id.to_string()),
+            block_height: env.block.height.into(),
+            timestamp: iso8601(env.block.time),
+            amount,
+            denom: denom.denom.clone(),
+            movement_type: CoinMovementType::Transfer,
+        }))
+        .add_attribute("action", "force_transfer")
+        .add_attribute("force_transfer_id", id)
+        .add_attribute("amount", amount)
+        .add_attribute("denom", &denom.denom)
+        .add_attribute("sender", sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("to_member_id", &to_member.id)
+        .add_attribute("reason", reason);
+    Ok(res)
+}
+
+// Return an error if the class of operations is paused.
+fn ensure_not_paused(state: &StateV2, scope: PauseScope) -> Result<(), ContractError> {
+    if state.paused.iter().any(|paused| paused.scope == scope) {
+        return Err(ContractError::Paused { scope });
+    }
+    Ok(())
+}
+
+// Load a registered denom, defaulting to the contract denom. The contract denom is registered
+// even if it predates the denom registry.
+fn load_denom(deps: Deps, state: &StateV2, denom: Option<String>) -> Result<Denom, ContractError> {
+    let denom = denom.unwrap_or_else(|| state.denom.clone());
+    match denoms_read(deps.storage).may_load(denom.as_bytes())? {
+        Some(denom) => Ok(denom),
+        None if denom == state.denom => Ok(Denom::enabled(denom)),
+        None => Err(ContractError::DenomNotFound { denom }),
+    }
+}
+
+// Load a registered denom, and return an error if it is disabled or the class of operations is
+// paused for the contract or the denom.
+fn load_active_denom(
+    deps: Deps,
+    state: &StateV2,
+    denom: Option<String>,
+    scope: PauseScope,
+) -> Result<Denom, ContractError> {
+    ensure_not_paused(state, scope.clone())?;
+    let denom = load_denom(deps, state, denom)?;
+    if !denom.enabled {
+        return Err(ContractError::DenomDisabled { denom: denom.denom });
+    }
+    if denom.paused.iter().any(|paused| paused.scope == scope) {
+        return Err(ContractError::Paused { scope });
+    }
+    Ok(denom)
+}
+
+// List the registered denoms, including the contract denom.
+fn registered_denoms(deps: Deps, state: &StateV2) -> StdResult<Vec<Denom>> {
+    let mut registered = denoms_read(deps.storage)
+        .range(None, None, Order::Ascending)
+        .map(|item| item.map(|(_, denom)| denom))
+        .collect::<StdResult<Vec<Denom>>>()?;
+    if !registered.iter().any(|denom| denom.denom == state.denom) {
+        registered.insert(0, Denom::enabled(state.denom.clone()));
+    }
+    Ok(registered)
+}
+
+// The allowance namespace of a denom, where the contract denom has none.
+fn allowance_denom<'a>(state: &StateV2, denom: &'a Denom) -> Option<&'a str> {
+    if denom.denom == state.denom {
+        None
+    } else {
+        Some(&denom.denom)
+    }
+}
+
+// The outstanding supply of a denom minted by a member.
+fn member_supply(
+    deps: Deps,
+    state: &StateV2,
+    member: &MemberV2,
+    denom: &str,
+) -> StdResult<Uint128> {
+    if denom == state.denom {
+        return Ok(member.supply);
+    }
+    Ok(denom_supplies_read(deps.storage, denom)
+        .may_load(member.id.as_bytes())?
+        .unwrap_or_default())
+}
+
+// The outstanding supply of a denom minted by a member after minting the amount, ensuring it
+// does not exceed the member's max supply.
+fn next_member_supply(
+    deps: Deps,
+    state: &StateV2,
+    member: &MemberV2,
+    denom: &str,
+    amount: Uint128,
+) -> Result<Uint128, ContractError> {
+    let supply = member_supply(deps, state, member, denom)?
+        .checked_add(amount)
+        .map_err(StdError::from)?;
+    if let Some(max_supply) = member_max_supply(deps, state, member, denom)? {
+        if supply > max_supply {
+            return Err(contract_err("mint exceeds member max supply"));
+        }
+    }
+    Ok(supply)
+}
+
+// The max outstanding supply of a denom a member can mint, if capped.
+fn member_max_supply(
+    deps: Deps,
+    state: &StateV2,
+    member: &MemberV2,
+    denom: &str,
+) -> StdResult<Option<Uint128>> {
+    if denom == state.denom {
+        return Ok(member.max_supply);
+    }
+    denom_max_supplies_read(deps.storage, denom).may_load(member.id.as_bytes())
+}
+
+// Save the outstanding supply of a denom minted by a member.
+fn save_member_supply(
+    deps: DepsMut,
+    state: &StateV2,
+    member: &mut MemberV2,
+    denom: &str,
+    supply: Uint128,
+) -> StdResult<()> {
+    if denom == state.denom {
+        member.supply = supply;
+        return members(deps.storage).save(member.id.as_bytes(), member);
+    }
+    denom_supplies(deps.storage, denom).save(member.id.as_bytes(), &supply)
+}
+
+// Ensure the account holds no token of any registered denom.
+fn ensure_no_token_balance(
+    deps: Deps,
+    state: &StateV2,
+    address: &Addr,
+) -> Result<(), ContractError> {
+    for denom in registered_denoms(deps, state)? {
+        let balance = deps.querier.query_balance(address, &denom.denom)?;
+        if !balance.amount.is_zero() {
+            return Err(contract_err("member still holds a token balance"));
+        }
+    }
+    Ok(())
+}
+
+// A helper function for creating generic contract errors.
+fn contract_err(s: &str) -> ContractError {
+    ContractError::Std(StdError::generic_err(s))
+}
+
+// Load a member by address, otherwise return an error.
+fn load_member(deps: Deps, address: &Addr) -> Result<MemberV2, ContractError> {
+    match members_read(deps.storage).may_load(address.as_bytes())? {
+        Some(member) => Ok(member),
+        None => Err(ContractError::MemberNotFound {
+            id: address.to_string(),
+        }),
+    }
+}
+
+// Return the member owning the first matched kyc attribute on the account, otherwise return an
+// error.
+fn matched_member(deps: Deps, addr: &Addr) -> Result<MemberV2, ContractError> {
+    let querier = AttributeQuerier::new(&deps.querier);
+    let mut pagination = None;
+    loop {
+        // Look up the account attributes in the kyc attribute index
+        let res = querier.attributes(addr.to_string(), pagination)?;
+        for attribute in res.attributes {
+            if let Some(member_id) =
+                kyc_attr_index_read(deps.storage).may_load(attribute.name.as_bytes())?
+            {
+                return Ok(members_read(deps.storage).load(member_id.as_bytes())?);
+            }
+        }
+        // Continue with the next page of attributes, if any
+        pagination = match res.pagination {
+            Some(page) if !page.next_key.is_empty() => Some(PageRequest {
+                key: page.next_key,
+                offset: 0,
+                limit: 0,
+                count_total: false,
+                reverse: false,
+            }),
+            _ => break,
+        };
+    }
+    Err(ContractError::MissingKycAttr {
+        addr: addr.to_string(),
+    })
+}
+
+// Ensure the account has one of the member kyc attributes, otherwise return an error.
+fn ensure_member_kyc(deps: Deps, addr: &Addr, member: &MemberV2) -> Result<(), ContractError> {
+    let querier = AttributeQuerier::new(&deps.querier);
+    for kyc_attr in member.kyc_attrs.iter() {
+        let res = querier.attribute(addr.to_string(), kyc_attr.to_string(), None)?;
+        if !res.attributes.is_empty() {
+            return Ok(());
+        }
+    }
+    Err(ContractError::MissingKycAttr {
+        addr: addr.to_string(),
+    })
+}
+
+/// Query contract state
+#[entry_point]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<QueryResponse, ContractError> {
+    match msg {
+        QueryMsg::GetMembers { start_after, limit } => try_get_members(deps, start_after, limit),
+        QueryMsg::GetMember { id } => try_get_member(deps, id),
+        QueryMsg::GetMemberSupply { id, denom } => try_get_member_supply(deps, id, denom),
+        QueryMsg::GetMemberByKycAttr { kyc_attr } => try_get_member_by_kyc_attr(deps, kyc_attr),
+        QueryMsg::GetExecutorConfig { id } => try_get_executor_config(deps, id),
+        QueryMsg::GetAllowance {
+            holder,
+            executor,
+            denom,
+        } => try_get_allowance(deps, holder, executor, denom),
+        QueryMsg::GetMintByReference { member, reference } => {
+            try_get_mint_by_reference(deps, member, reference)
+        }
+        QueryMsg::GetMintApproval { id } => try_get_mint_approval(deps, id),
+        QueryMsg::GetPendingMint { id } => try_get_pending_mint(deps, id),
+        QueryMsg::GetPendingMints { start_after, limit } => {
+            try_get_pending_mints(deps, env, start_after, limit)
+        }
+        QueryMsg::GetRedemption {
+            member_id,
+            reference,
+        } => try_get_redemption(deps, member_id, reference),
+        QueryMsg::GetRedemptions {
+            member_id,
+            start_after,
+            limit,
+        } => try_get_redemptions(deps, member_id, start_after, limit),
+        QueryMsg::GetFrozenAddress { id } => try_get_frozen_address(deps, id),
+        QueryMsg::GetForceTransfers {
+            id,
+            start_after,
+            limit,
+        } => try_get_force_transfers(deps, id, start_after, limit),
+        QueryMsg::GetDenom { denom } => try_get_denom(deps, denom),
+        QueryMsg::GetDenoms {} => try_get_denoms(deps),
+        QueryMsg::GetJoinProposal { id } => try_get_join_proposal(deps, id),
+        QueryMsg::GetContractInfo {} => try_get_contract_info(deps),
+        QueryMsg::GetVersionInfo {} => try_get_version_info(deps),
+    }
+}
+
+// Query a page of members.
+fn try_get_members(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<QueryResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    // Bucket ranges are inclusive of the start key, so skip past the provided member ID.
+    let start = start_after.map(|id| {
+        let mut key = id.into_bytes();
+        key.push(0);
+        key
+    });
+
+    // Read one extra member to determine whether there is another page.
+    let mut members = members_read(deps.storage)
+        .range(start.as_deref(), None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| {
+            let (_, member) = item?;
+            Ok(member)
+        })
+        .collect::<Result<Vec<MemberV2>, ContractError>>()?;
+
+    let next_key = if members.len() > limit {
+        members.truncate(limit);
+        members.last().map(|member| member.id.to_string())
+    } else {
+        None
+    };
+
+    Ok(to_binary(&Members { members, next_key })?)
+}
+
+// Query member by ID.
+fn try_get_member(deps: Deps, id: String) -> Result<QueryResponse, ContractError> {
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+    let member = members_read(deps.storage).load(key)?;
+    let bin = to_binary(&member)?;
+    Ok(bin)
+}
+
+// Query the outstanding supply of a denom minted by a member.
+fn try_get_member_supply(
+    deps: Deps,
+    id: String,
+    denom: Option<String>,
+) -> Result<QueryResponse, ContractError> {
+    let address = deps.api.addr_validate(&id)?;
+    let member = members_read(deps.storage).load(address.as_bytes())?;
+    let state = config_read(deps.storage).load()?;
+    let denom = load_denom(deps, &state, denom)?;
+    let bin = to_binary(&MemberSupply {
+        supply: member_supply(deps, &state, &member, &denom.denom)?,
+        max_supply: member_max_supply(deps, &state, &member, &denom.denom)?,
+        id: member.id,
+        denom: denom.denom,
+    })?;
+    Ok(bin)
+}
+
+// Query member by owned kyc attribute.
+fn try_get_member_by_kyc_attr(
+    deps: Deps,
+    kyc_attr: String,
+) -> Result<QueryResponse, ContractError> {
+    let member_id = kyc_attr_index_read(deps.storage).load(kyc_attr.trim().as_bytes())?;
+    let member = members_read(deps.storage).load(member_id.as_bytes())?;
+    let bin = to_binary(&member)?;
+    Ok(bin)
+}
+
+// Query executor spending limits by executor ID.
+fn try_get_executor_config(deps: Deps, id: String) -> Result<QueryResponse, ContractError> {
+    let address = deps.api.addr_validate(&id)?;
+    let state = config_read(deps.storage).load()?;
+    if !state.executors.contains(&address.to_string()) {
+        return Err(contract_err("executor does not exist"));
+    }
+    // Executors added before spending limits existed have no config.
+    let executor_config = executor_configs_read(deps.storage)
+        .may_load(address.as_bytes())?
+        .unwrap_or_else(|| ExecutorConfig::unlimited(address));
+    let bin = to_binary(&executor_config)?;
+    Ok(bin)
+}
+
+// Query the amount an executor can transfer from a holder.
+fn try_get_allowance(
+    deps: Deps,
+    holder: String,
+    executor: String,
+    denom: Option<String>,
+) -> Result<QueryResponse, ContractError> {
+    let holder = deps.api.addr_validate(&holder)?;
+    let executor = deps.api.addr_validate(&executor)?;
+    let state = config_read(deps.storage).load()?;
+    let denom = load_denom(deps, &state, denom)?;
+    let allowance = allowances_read(deps.storage, &holder, allowance_denom(&state, &denom))
+        .may_load(executor.as_bytes())?
+        .unwrap_or(Allowance {
+            holder,
+            executor,
+            amount: Uint128::zero(),
+        });
+    let bin = to_binary(&allowance)?;
+    Ok(bin)
+}
+
+// Query a member's mint by deposit reference.
+fn try_get_mint_by_reference(
+    deps: Deps,
+    member: String,
+    reference: String,
+) -> Result<QueryResponse, ContractError> {
+    let member = deps.api.addr_validate(&member)?;
+    let record = mint_references_read(deps.storage, &member).load(reference.as_bytes())?;
+    let bin = to_binary(&record)?;
+    Ok(bin)
+}
+
+// Query the mint approval threshold of a member.
+fn try_get_mint_approval(deps: Deps, id: String) -> Result<QueryResponse, ContractError> {
+    let address = deps.api.addr_validate(&id)?;
+    let approval = mint_approval_configs_read(deps.storage).load(address.as_bytes())?;
+    let bin = to_binary(&approval)?;
+    Ok(bin)
+}
+
+// Query pending mint by ID.
+fn try_get_pending_mint(deps: Deps, id: Uint128) -> Result<QueryResponse, ContractError> {
+    let pending_mint = pending_mints_read(deps.storage).load(&id.u128().to_be_bytes())?;
+    let bin = to_binary(&pending_mint)?;
+    Ok(bin)
+}
+
+// Query pending mints that can still be approved in pages ordered by ID.
+fn try_get_pending_mints(
+    deps: Deps,
+    env: Env,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> Result<QueryResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    // Bucket ranges are inclusive of the start key, so start at the next ID.
+    let start = match start_after {
+        None => None,
+        Some(id) => match id.u128().checked_add(1) {
+            Some(next) => Some(next.to_be_bytes()),
+            // No pending mint can follow the largest ID.
+            None => {
+                return Ok(to_binary(&PendingMints {
+                    pending_mints: vec![],
+                    next_key: None,
+                })?)
+            }
+        },
+    };
+
+    // Read one extra pending mint to determine whether there is another page.
+    let height = Uint128::from(env.block.height);
+    let mut pending_mints = pending_mints_read(deps.storage)
+        .range(start.as_ref().map(|key| &key[..]), None, Order::Ascending)
+        .map(|item| {
+            let (_, pending_mint) = item?;
+            Ok(pending_mint)
+        })
+        .filter(|pending_mint: &StdResult<PendingMint>| {
+            pending_mint
+                .as_ref()
+                .map_or(true, |pending_mint| height <= pending_mint.expires)
+        })
+        .take(limit + 1)
+        .collect::<StdResult<Vec<PendingMint>>>()?;
+
+    let next_key = if pending_mints.len() > limit {
+        pending_mints.truncate(limit);
+        pending_mints.last().map(|pending_mint| pending_mint.id)
+    } else {
+        None
+    };
+
+    Ok(to_binary(&PendingMints {
+        pending_mints,
+        next_key,
+    })?)
+}
+
+// Query an open redemption by member ID and reference.
+fn try_get_redemption(
+    deps: Deps,
+    member_id: String,
+    reference: String,
+) -> Result<QueryResponse, ContractError> {
+    let member = deps.api.addr_validate(&member_id)?;
+    let redemption = redemptions_read(deps.storage, &member).load(reference.as_bytes())?;
+    let bin = to_binary(&redemption)?;
+    Ok(bin)
+}
+
+// Query the open redemptions of a member in pages ordered by reference.
+fn try_get_redemptions(
+    deps: Deps,
+    member_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<QueryResponse, ContractError> {
+    let member = deps.api.addr_validate(&member_id)?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    // Bucket ranges are inclusive of the start key, so skip past the provided reference.
+    let start = start_after.map(|reference| {
+        let mut key = reference.into_bytes();
+        key.push(0);
+        key
+    });
+
+    // Read one extra redemption to determine whether there is another page.
+    let mut redemptions = redemptions_read(deps.storage, &member)
+        .range(start.as_deref(), None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| {
+            let (_, redemption) = item?;
+            Ok(redemption)
+        })
+        .collect::<Result<Vec<Redemption>, ContractError>>()?;
+
+    let next_key = if redemptions.len() > limit {
+        redemptions.truncate(limit);
+        redemptions
+            .last()
+            .map(|redemption| redemption.reference.clone())
+    } else {
+        None
+    };
+
+    Ok(to_binary(&Redemptions {
+        redemptions,
+        next_key,
+    })?)
+}
+
+// Query the compliance hold on an address.
+fn try_get_frozen_address(deps: Deps, id: String) -> Result<QueryResponse, ContractError> {
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+    let frozen_address = frozen_addresses_read(deps.storage).load(key)?;
+    let bin = to_binary(&frozen_address)?;
+    Ok(bin)
+}
+
+// Query the force transfers out of an address in pages ordered by ID.
+fn try_get_force_transfers(
+    deps: Deps,
+    id: String,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> Result<QueryResponse, ContractError> {
+    let address = deps.api.addr_validate(&id)?;
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+
+    // Bucket ranges are inclusive of the start key, so start at the next ID.
+    let start = match start_after {
+        None => None,
+        Some(id) => match id.u128().checked_add(1) {
+            Some(next) => Some(next.to_be_bytes()),
+            // No force transfer can follow the largest ID.
+            None => {
+                return Ok(to_binary(&ForceTransfers {
+                    force_transfers: vec![],
+                    next_key: None,
+                })?)
+            }
+        },
+    };
+
+    // Read one extra force transfer to determine whether there is another page.
+    let mut force_transfers = force_transfers_read(deps.storage, &address)
+        .range(start.as_ref().map(|key| &key[..]), None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| {
+            let (_, force_transfer) = item?;
+            Ok(force_transfer)
+        })
+        .collect::<StdResult<Vec<ForceTransfer>>>()?;
+
+    let next_key = if force_transfers.len() > limit {
+        force_transfers.truncate(limit);
+        force_transfers
+            .last()
+            .map(|force_transfer| force_transfer.id)
+    } else {
+        None
+    };
+
+    Ok(to_binary(&ForceTransfers {
+        force_transfers,
+        next_key,
+    })?)
+}
+
+// Query registered denom.
+fn try_get_denom(deps: Deps, denom: String) -> Result<QueryResponse, ContractError> {
+    let state = config_read(deps.storage).load()?;
+    let denom = load_denom(deps, &state, Some(denom))?;
+    let bin = to_binary(&denom)?;
+    Ok(bin)
+}
+
+// Query all registered denoms.
+fn try_get_denoms(deps: Deps) -> Result<QueryResponse, ContractError> {
+    let state = config_read(deps.storage).load()?;
+    let denoms = registered_denoms(deps, &state)?;
+    let bin = to_binary(&denoms)?;
+    Ok(bin)
+}
+
+// Query join proposal by proposed member ID.
+fn try_get_join_proposal(deps: Deps, id: String) -> Result<QueryResponse, ContractError> {
+    let address = deps.api.addr_validate(&id)?;
+    let key = address.as_bytes();
+    let proposal = join_proposals_read(deps.storage).load(key)?;
+    let bin = to_binary(&proposal)?;
+    Ok(bin)
+}
+
+// Query contract state
+fn try_get_contract_info(deps: Deps) -> Result<QueryResponse, ContractError> {
+    let state = &config_read(deps.storage).load()?;
+    let bin = to_binary(state)?;
+    Ok(bin)
+}
+
+// Get contract version
+fn try_get_version_info(deps: Deps) -> Result<QueryResponse, ContractError> {
+    let version = &cw2::get_contract_version(deps.storage)?;
+    let bin = to_binary(version)?;
+    Ok(bin)
+}
+
+fn get_marker(id: String, querier: &MarkerQuerier<Empty>) -> StdResult<MarkerAccount> {
+    let response = querier.marker(id)?;
+    if let Some(marker) = response.marker {
+        return if let Ok(account) = MarkerAccount::try_from(marker) {
+            Ok(account)
+        } else {
+            Err(StdError::generic_err("unable to type-cast marker account"))
+        };
+    } else {
+        Err(StdError::generic_err("no marker found for id"))
+    }
+}
+
+/// Called when migrating a contract instance to a new code ID.
+#[entry_point]
+pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let ver_result = get_contract_version(deps.storage);
+    let ver = match ver_result {
+        Ok(ver) => ver,
+        // Default if running older version never set
+        _ => ContractVersion {
+            contract: CONTRACT_NAME.into(),
+            version: "0.0.1".into(),
+        },
+    };
+
+    // Sanity check contract name for match.
+    if ver.contract != CONTRACT_NAME {
+        return Err(contract_err("can only upgrade from same type"));
+    }
+
+    // Ensure we are upgrading to a newer version only.
+    let current_version = Version::parse(&ver.version)?;
+    let new_version = Version::parse(CONTRACT_VERSION)?;
+    if current_version >= new_version {
+        return Err(contract_err("cannot upgrade from same or newer version"));
+    }
+
+    // migrate state
+    migrate_state(deps.branch(), current_version.clone(), &msg)?;
+
+    // migrate denoms
+    migrate_denoms(deps.branch(), current_version.clone(), &msg)?;
+
+    // migrate join proposals
+    migrate_join_proposals(deps.branch(), current_version.clone(), &msg)?;
+
+    // migrate members
+    migrate_members(deps.branch(), current_version, &msg)?;
+
+    // lastly, migrate version
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(deprecated)]
+    use crate::join_proposal::{legacy_join_proposals, JoinProposal};
+    #[allow(deprecated)]
+    use crate::member::{legacy_members, Member};
+    #[allow(deprecated)]
+    use crate::msg::VoteChoice;
+    #[allow(deprecated)]
+    use crate::state::{legacy_config, State};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, Binary, CosmosMsg, Decimal};
+    use k256::ecdsa::signature::Signer;
+    use k256::ecdsa::{Signature, SigningKey};
+    use prost::Message;
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::shim::Any;
+    use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributeRequest, QueryAttributeResponse,
+        QueryAttributesRequest, QueryAttributesResponse,
+    };
+    use provwasm_std::types::provenance::marker::v1::{
+        MarkerStatus, QueryMarkerRequest, QueryMarkerResponse,
+    };
+    use std::convert::TryInto;
+
+    #[test]
+    fn valid_init() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Init
+        let res = instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Ensure messages were created.
+        assert_eq!(1, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgAddFinalizeActivateMarkerRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "0".to_string(),
+                    }),
+                    manager: env.contract.address.to_string(),
+                    from_address: env.contract.address.to_string(),
+                    marker_type: MarkerType::Restricted.into(),
+                    access_list: vec![
+                        AccessGrant {
+                            address: env.contract.address.to_string(),
+                            permissions: vec![1, 2, 3, 4, 5, 6, 7],
+                        },
+                        AccessGrant {
+                            address: "admin".to_string(),
+                            permissions: vec![6],
+                        },
+                    ],
+                    supply_fixed: false,
+                    allow_governance_control: false,
+                    allow_forced_transfer: true,
+                    required_attributes: vec![],
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(
+                    type_url,
+                    "/provenance.marker.v1.MsgAddFinalizeActivateMarkerRequest"
+                );
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+
+        // Read state
+        let config_state = config_read(&deps.storage).load().unwrap();
+
+        // Validate state values
+        assert_eq!(config_state.denom, "dcc.coin");
+
+        let contract_version = get_contract_version(&deps.storage).unwrap();
+
+        assert_eq!(contract_version.contract, CONTRACT_NAME);
+        assert_eq!(contract_version.version, CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn join_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Bank join as member.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        let addr = Addr::unchecked("bank");
+        let key: &[u8] = addr.as_bytes();
+        let member = members_read(&deps.storage).load(key).unwrap();
+
+        assert_eq!(member.id, "bank");
+        assert_eq!(member.joined, Uint128::new(12345));
+        assert_eq!(member.kyc_attrs, vec!["bank.kyc.pb"]);
+        assert_eq!(member.name, "bank");
+        assert_eq!(member.supply, Uint128::zero());
+        assert_eq!(member.max_supply, None);
+    }
+
+    #[test]
+    fn join_admin_bypass() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Bootstrap the first member.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank1".into(),
+                name: "bank1".into(),
+                kyc_attrs: vec!["bank1.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Ensure members cannot bypass the member vote.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::Join {
+                id: "bank2".into(),
+                name: "bank2".into(),
+                kyc_attrs: vec!["bank2.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        // Ensure admin can still add a member without a proposal.
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank2".into(),
+                name: "bank2".into(),
+                kyc_attrs: vec!["bank2.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+        assert_eq!(res.attributes[0].value, "join");
+        assert!(members_read(&deps.storage)
+            .may_load(b"bank2")
+            .unwrap()
+            .is_some());
+        assert!(join_proposals_read(&deps.storage)
+            .may_load(b"bank2")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn join_invalid_params() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Try to create join member w/ too short name
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "ban".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "invalid name too short")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to create join member w/ empty kyc attr
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec![" ".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "kyc attribute name is empty")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to create join member not admin
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to send funds w/ the join proposal
+        let funds = coin(1000, "nhash");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[funds]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to create with same kyc attributes.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec![
+                    "bank.kyc.pb".into(),
+                    "bank2.kyc.pb".into(),
+                    "bank3.kyc.pb".into(),
+                    "bank.kyc.pb".into(),
+                ],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "duplicate kyc attributes in args")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn join_dup_kyc_attribute() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Bank join as member.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank1".into(),
+                kyc_attrs: vec!["bank1.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to create a duplicate kyc attribute.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank2".into(),
+                name: "bank2".into(),
+                kyc_attrs: vec!["bank1.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::DuplicateKycAttr { attr } => assert_eq!(attr, "bank1.kyc.pb"),
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn join_dup_member_id() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Bank join as member.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to create a duplicate join proposal.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank2".into(),
+                kyc_attrs: vec!["bank2.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "duplicate member")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn remove_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Set the removal delay
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetRemovalDelay {
+                blocks: Uint128::new(100),
+            },
+        )
+        .unwrap();
+
+        let key = "bank".as_bytes();
+        let mut member = members_read(&deps.storage).may_load(key).unwrap();
+        assert!(member.is_some());
+
+        // Remove member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Remove { id: "bank".into() },
+        )
+        .unwrap();
+
+        // Ensure the member is being removed.
+        member = members_read(&deps.storage).may_load(key).unwrap();
+        assert_eq!(member.unwrap().removal_height, Some(Uint128::new(12445)));
+
+        // Try to finalize the removal before the delay has passed.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::FinalizeRemoval { id: "bank".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg }) => {
+                assert_eq!(msg, "member removal delay has not passed")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Finalize the removal after the delay.
+        let mut env = mock_env();
+        env.block.height = 12445;
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("admin", &[]),
+            ExecuteMsg::FinalizeRemoval { id: "bank".into() },
+        )
+        .unwrap();
+
+        member = members_read(&deps.storage).may_load(key).unwrap();
+        assert!(member.is_none());
+
+        // Ensure the kyc attributes were removed from the index.
+        let member_id = kyc_attr_index_read(&deps.storage)
+            .may_load(b"bank.kyc.pb")
+            .unwrap();
+        assert!(member_id.is_none());
+    }
+
+    #[test]
+    fn remove_param_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to remove a member that doesn't exist.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Remove { id: "bank1".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MemberNotFound { id } => assert_eq!(id, "bank1"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to remove a member not as admin.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Remove { id: "bank".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to send funds with the cancel message.
+        let funds = coin(1000, "nhash");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[funds]),
+            ExecuteMsg::Remove { id: "bank".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to remove a member that still holds tokens.
+        let dcc = coin(100, "dcc.coin");
+        deps.querier.mock_querier.update_balance("bank", vec![dcc]);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Remove { id: "bank".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg }) => {
+                assert_eq!(msg, "member still holds a token balance")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn finalize_removal_param_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to finalize removal of a member that is not being removed.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::FinalizeRemoval { id: "bank".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg }) => {
+                assert_eq!(msg, "member is not being removed")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Remove member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Remove { id: "bank".into() },
+        )
+        .unwrap();
+
+        // Try to finalize removal not as admin.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::FinalizeRemoval { id: "bank".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to finalize removal of a member that received tokens during removal.
+        let dcc = coin(100, "dcc.coin");
+        deps.querier.mock_querier.update_balance("bank", vec![dcc]);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::FinalizeRemoval { id: "bank".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg }) => {
+                assert_eq!(msg, "member still holds a token balance")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to mint as a member being removed.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MemberRemoving { id } => assert_eq!(id, "bank"),
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn transfer_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Assume the customer has a balance of tokens + the required attribute.
+        let dcc = coin(1000, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![dcc]);
+
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Transfer dcc from the customer to a member bank.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(500),
+                recipient: "bank".into(),
+                denom: None,
+            },
+        )
+        .unwrap();
+
+        // Ensure message was created.
+        assert_eq!(1, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgTransferRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "500".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                    from_address: "customer".to_string(),
+                    to_address: "bank".to_string(),
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgTransferRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+
+        // Ensure the coin movement event was emitted.
+        assert_eq!(1, res.events.len());
+        assert_eq!(res.events[0].ty, "coin_movement");
+        assert_eq!(
+            CoinMovement::try_from(&res.events[0]).unwrap(),
+            CoinMovement {
+                from_address: Addr::unchecked("customer"),
+                from_address_bank_uuid: Some("bank".into()),
+                to_address: Addr::unchecked("bank"),
+                to_address_bank_uuid: Some("bank".into()),
+                block_height: Uint128::new(12345),
+                timestamp: iso8601(env.block.time),
+                amount: Uint128::new(500),
+                denom: "dcc.coin".into(),
+                movement_type: CoinMovementType::Transfer,
+            }
+        );
+    }
+
+    #[test]
+    fn transfer_to_removed_member() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Start removing the member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Remove { id: "bank".into() },
+        )
+        .unwrap();
+
+        // Assume the customer has a balance of tokens + the required attribute.
+        let dcc = coin(1000, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![dcc]);
+
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Try to transfer dcc from the customer to the member bank being removed.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(500),
+                recipient: "bank".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "recipient member is being removed")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to transfer dcc to another customer of the member bank being removed.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(500),
+                recipient: "customer2".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "recipient member is being removed")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn transfer_param_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to transfer zero dcc from the customer to a member bank.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::zero(),
+                recipient: "bank".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "invalid transfer amount")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to send additional funds during transfer.
+        let funds = coin(1000, "nhash");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("customer", &[funds]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(500),
+                recipient: "bank".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn transfer_insufficient_funds() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Assume the customer has the required attribute, but no tokens.
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Try to transfer dcc from the customer to the member bank.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(500),
+                recipient: "bank".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::InsufficientBalance { needed, available } => {
+                assert_eq!(needed, Uint128::new(500));
+                assert_eq!(available, Uint128::zero());
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn transfer_without_from_attribute() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Assume the customer has a balance of dcc, but does NOT have the required attribute.
+        let dcc = coin(1000, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![dcc]);
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: "customer".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+
+        // Try to transfer dcc from the customer to a member bank.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(500),
+                recipient: "bank".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MissingKycAttr { addr } => assert_eq!(addr, "customer"),
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn transfer_without_to_attribute() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Assume the customer has a balance of tokens + the required attribute.
+        let dcc = coin(1000, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![dcc]);
+
+        // TODO - fix test since mock response returns same result no matter the input
+        // QueryAttributesRequest::mock_response(
+        //     &mut deps.querier,
+        //     QueryAttributesResponse {
+        //         account: "customer".to_string(),
+        //         attributes: vec![Attribute {
+        //             name: "bank.kyc.pb".to_string(),
+        //             value: "ok".as_bytes().to_vec(),
+        //             attribute_type: AttributeType::String.into(),
+        //             address: "".to_string(),
+        //         }],
+        //         pagination: None,
+        //     },
+        // );
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: "customer".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+
+        // Transfer dcc from the customer to a member bank.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(500),
+                recipient: "customer2".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MissingKycAttr { .. } => {
+                // TODO - reenable after mock response return is fixed
+                // assert_eq!(addr, "customer2")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn mint_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Mint reserve tokens and withdraw them.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap();
+
+        // Ensure messages were created.
+        assert_eq!(2, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgMintRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "100".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgMintRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+
+        match &res.messages[1].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgWithdrawRequest {
+                    denom: "dcc.coin".to_string(),
+                    administrator: env.contract.address.to_string(),
+                    to_address: "bank".to_string(),
+                    amount: vec![Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "100".to_string(),
+                    }],
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgWithdrawRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+
+        // Ensure the coin movement event was emitted.
+        assert_eq!(1, res.events.len());
+        assert_eq!(res.events[0].ty, "coin_movement");
+        assert_eq!(
+            CoinMovement::try_from(&res.events[0]).unwrap(),
+            CoinMovement {
+                from_address: Addr::unchecked("cosmos2contract"),
+                from_address_bank_uuid: None,
+                to_address: Addr::unchecked("bank"),
+                to_address_bank_uuid: Some("bank".into()),
+                block_height: Uint128::new(12345),
+                timestamp: iso8601(env.block.time),
+                amount: Uint128::new(100),
+                denom: "dcc.coin".into(),
+                movement_type: CoinMovementType::Mint,
+            }
+        );
+
+        // Ensure the member supply was increased.
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.supply, Uint128::new(100));
+    }
+
+    #[test]
+    fn mint_with_reference() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Mint reserve tokens against a deposit.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: Some("deposit-1".into()),
+            },
+        )
+        .unwrap();
+        assert_eq!(2, res.messages.len());
+        assert!(res
+            .attributes
+            .iter()
+            .any(|a| a.key == "reference" && a.value == "deposit-1"));
+
+        // Ensure the deposit can be looked up.
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetMintByReference {
+                member: "bank".into(),
+                reference: "deposit-1".into(),
+            },
+        )
+        .unwrap();
+        let record: MintRecord = from_binary(&bin).unwrap();
+        assert_eq!(
+            record,
+            MintRecord {
+                reference: "deposit-1".into(),
+                member: Addr::unchecked("bank"),
+                amount: Uint128::new(100),
+                denom: "dcc.coin".into(),
+                address: Addr::unchecked("bank"),
+                height: Uint128::new(12345),
+            }
+        );
+
+        // Ensure a retried mint is rejected.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: Some("deposit-1".into()),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::DuplicateMintReference { reference } => {
+                assert_eq!(reference, "deposit-1")
+            }
+            _ => panic!("unexpected error: {:?}", err),
+        }
+
+        // Ensure the supply was only increased once.
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.supply, Uint128::new(100));
+
+        // Ensure unknown deposits are not found.
+        let err = query(
+            deps.as_ref(),
+            env,
+            QueryMsg::GetMintByReference {
+                member: "bank".into(),
+                reference: "deposit-2".into(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Std(StdError::NotFound { .. })));
+    }
+
+    #[test]
+    fn mint_approval() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Ensure the member cannot approve its own mints.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetMintApproval {
+                id: "bank".into(),
+                threshold: Some(Uint128::new(100)),
+                approver: Some("bank".into()),
+                window_blocks: Uint128::new(10),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "mint approver cannot be the member")
+            }
+            _ => panic!("unexpected error: {:?}", err),
+        }
+
+        // Require approval of mints above 100.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetMintApproval {
+                id: "bank".into(),
+                threshold: Some(Uint128::new(100)),
+                approver: Some("checker".into()),
+                window_blocks: Uint128::new(10),
+            },
+        )
+        .unwrap();
+
+        // Mints at the threshold are issued immediately.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(2, res.messages.len());
+
+        // Mints above the threshold wait for approval.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(500),
+                address: None,
+                denom: None,
+                reference: Some("deposit-1".into()),
+            },
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        assert!(res.events.is_empty());
+        assert_eq!(res.attributes[0].value, "request_mint");
+        assert_eq!(res.attributes[1].value, "1");
+
+        let pending_mint: PendingMint = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetPendingMint {
+                    id: Uint128::new(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(pending_mint.amount, Uint128::new(500));
+        assert_eq!(pending_mint.expires, Uint128::new(12355));
+
+        // Ensure the pending deposit reference cannot be minted again.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(500),
+                address: None,
+                denom: None,
+                reference: Some("deposit-1".into()),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateMintReference { .. }));
+
+        // Ensure only the approver or admin can approve.
+        for sender in ["bank", "other"] {
+            let err = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(sender, &[]),
+                ExecuteMsg::ApproveMint {
+                    id: Uint128::new(1),
+                },
+            )
+            .unwrap_err();
+            assert!(matches!(err, ContractError::Unauthorized {}));
+        }
+
+        // Approve the mint.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("checker", &[]),
+            ExecuteMsg::ApproveMint {
+                id: Uint128::new(1),
+            },
+        )
+        .unwrap();
+        assert_eq!(2, res.messages.len());
+        assert_eq!(1, res.events.len());
+        assert_eq!(res.attributes[0].value, "mint");
+
+        // Ensure the supply was increased and the deposit recorded.
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.supply, Uint128::new(600));
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetMintByReference {
+                member: "bank".into(),
+                reference: "deposit-1".into(),
+            },
+        )
+        .unwrap();
+        assert!(pending_mints_read(&deps.storage)
+            .may_load(&1u128.to_be_bytes())
+            .unwrap()
+            .is_none());
+
+        // Request another mint and let it expire.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(200),
+                address: None,
+                denom: None,
+                reference: Some("deposit-2".into()),
+            },
+        )
+        .unwrap();
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetPendingMints {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let pending: PendingMints = from_binary(&bin).unwrap();
+        assert_eq!(pending.pending_mints.len(), 1);
+        assert_eq!(pending.pending_mints[0].id, Uint128::new(2));
+        assert_eq!(pending.next_key, None);
+
+        let mut expired_env = mock_env();
+        expired_env.block.height += 11;
+        let err = execute(
+            deps.as_mut(),
+            expired_env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::ApproveMint {
+                id: Uint128::new(2),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::PendingMintExpired { id } => assert_eq!(id, Uint128::new(2)),
+            _ => panic!("unexpected error: {:?}", err),
+        }
+
+        // Ensure the expired deposit reference can be minted again.
+        let res = execute(
+            deps.as_mut(),
+            expired_env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(50),
+                address: None,
+                denom: None,
+                reference: Some("deposit-2".into()),
+            },
+        )
+        .unwrap();
+        assert_eq!(2, res.messages.len());
+        assert_eq!(res.attributes[0].value, "mint");
+        assert!(pending_mints_read(&deps.storage)
+            .may_load(&2u128.to_be_bytes())
+            .unwrap()
+            .is_none());
+
+        // Request another mint and reject it.
+        execute(
+            deps.as_mut(),
+            expired_env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(200),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap();
+        let res = execute(
+            deps.as_mut(),
+            expired_env,
+            mock_info("admin", &[]),
+            ExecuteMsg::RejectMint {
+                id: Uint128::new(3),
+            },
+        )
+        .unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(res.attributes[0].value, "reject_mint");
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetPendingMints {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let pending: PendingMints = from_binary(&bin).unwrap();
+        assert!(pending.pending_mints.is_empty());
+
+        // Request another mint and let it expire.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(200),
+                address: None,
+                denom: None,
+                reference: Some("deposit-4".into()),
+            },
+        )
+        .unwrap();
+
+        // Ensure the pending mint cannot be expired within its window.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExpirePendingMint {
+                id: Uint128::new(4),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "pending mint has not expired")
+            }
+            _ => panic!("unexpected error: {:?}", err),
+        }
+
+        // Ensure expired pending mints are left out of the query.
+        let mut expired_env = mock_env();
+        expired_env.block.height += 11;
+        let bin = query(
+            deps.as_ref(),
+            expired_env.clone(),
+            QueryMsg::GetPendingMints {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let pending: PendingMints = from_binary(&bin).unwrap();
+        assert!(pending.pending_mints.is_empty());
+
+        // Anyone can remove the expired pending mint.
+        let res = execute(
+            deps.as_mut(),
+            expired_env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExpirePendingMint {
+                id: Uint128::new(4),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.attributes[0].value, "expire_pending_mint");
+        assert!(pending_mints_read(&deps.storage)
+            .may_load(&4u128.to_be_bytes())
+            .unwrap()
+            .is_none());
+        assert!(
+            pending_mint_references_read(&deps.storage, &Addr::unchecked("bank"))
+                .may_load(b"deposit-4")
+                .unwrap()
+                .is_none()
+        );
+
+        // Ensure a removed pending mint cannot be approved.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::ApproveMint {
+                id: Uint128::new(4),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::PendingMintNotFound { id } => assert_eq!(id, Uint128::new(4)),
+            _ => panic!("unexpected error: {:?}", err),
+        }
+
+        // Ensure paging past the largest ID returns an empty page.
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetPendingMints {
+                start_after: Some(Uint128::MAX),
+                limit: None,
+            },
+        )
+        .unwrap();
+        let pending: PendingMints = from_binary(&bin).unwrap();
+        assert!(pending.pending_mints.is_empty());
+        assert_eq!(pending.next_key, None);
+
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.supply, Uint128::new(650));
+    }
+
+    #[test]
+    fn mint_param_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to mint zero reserve tokens
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::zero(),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg }) => {
+                assert_eq!(msg, "invalid mint amount")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to send funds with the mint message
+        let funds = coin(1000, "nhash");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[funds]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(1000),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Call mint as a non-member
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("non.member", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(1000),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MemberNotFound { id } => assert_eq!(id, "non.member"),
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn mint_withdraw_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Assume the customer has the required attribute.
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Mint reserve tokens and withdraw dcc to a customer address.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: Some("customer".into()),
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap();
+
+        // Ensure messages were created.
+        assert_eq!(2, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgMintRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "100".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgMintRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+
+        match &res.messages[1].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgWithdrawRequest {
+                    denom: "dcc.coin".to_string(),
+                    administrator: env.contract.address.to_string(),
+                    to_address: "customer".to_string(),
+                    amount: vec![Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "100".to_string(),
+                    }],
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgWithdrawRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+    }
+
+    #[test]
+    fn mint_withdraw_not_member_customer() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member 1
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank1".into(),
+                name: "bank1".into(),
+                kyc_attrs: vec!["bank1.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Create join member 2
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank2".into(),
+                name: "bank2".into(),
+                kyc_attrs: vec!["bank2.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // TODO - fix mock response so it is only returnable for checking bank2 and not bank1
+        // Assume the customer has the required attribute.
+        // QueryAttributeRequest::mock_response(
+        //     &mut deps.querier,
+        //     QueryAttributeResponse {
+        //         account: "customer".to_string(),
+        //         attributes: vec![Attribute {
+        //             name: "bank2.kyc.pb".to_string(),
+        //             value: "ok".as_bytes().to_vec(),
+        //             attribute_type: AttributeType::String.into(),
+        //             address: "".to_string(),
+        //         }],
+        //         pagination: None,
+        //     },
+        // );
+
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: "customer".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+
+        // Mint reserve tokens and withdraw dcc to a customer address.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: Some("customer".into()),
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MissingKycAttr { addr } => assert_eq!(addr, "customer"),
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn mint_withdraw_no_attribute() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: "customer".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+
+        // Mint reserve tokens and withdraw dcc to a customer address.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: Some("customer".into()),
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MissingKycAttr { addr } => assert_eq!(addr, "customer"),
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn mint_exceeds_max_supply() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Limit the member supply.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetMemberLimit {
+                id: "bank".into(),
+                max_supply: Some(Uint128::new(150)),
+                denom: None,
+            },
+        )
+        .unwrap();
+
+        // Mint up to the limit.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap();
+
+        // Try to mint past the limit.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(51),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg }) => {
+                assert_eq!(msg, "mint exceeds member max supply")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Ensure the member supply was not changed.
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.supply, Uint128::new(100));
+    }
+
+    #[test]
+    fn burn_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Burn needs to query the marker address, so we mock one here.
+        let expected_marker = MarkerAccount {
+            base_account: Some(BaseAccount {
+                address: "dcc.marker".to_string(),
+                pub_key: None,
+                account_number: 1,
+                sequence: 0,
+            }),
+            manager: env.contract.address.to_string(),
+            access_control: vec![AccessGrant {
+                address: "tp18vd8fpwxzck93qlwghaj6arh4p7c5n89x8kskz".to_string(),
+                permissions: vec![1, 2, 3, 4, 5, 6, 7],
+            }],
+            status: MarkerStatus::Active.into(),
+            denom: "dcc.coin".to_string(),
+            supply: "0".to_string(),
+            marker_type: MarkerType::Coin.into(),
+            supply_fixed: false,
+            allow_governance_control: false,
+            allow_forced_transfer: false,
+            required_attributes: vec![],
+        };
+
+        let mock_marker_response = QueryMarkerResponse {
+            marker: Some(Any {
+                type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                value: expected_marker.encode_to_vec(),
+            }),
+        };
+
+        QueryMarkerRequest::mock_response(&mut deps.querier, mock_marker_response);
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Mint reserve tokens.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap();
+
+        // Simulate balance update due to mint.
+        let minted = coin(100, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance("bank", vec![minted]);
+
+        // Burn reserve tokens.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Burn {
+                amount: Uint128::new(25),
+                denom: None,
+            },
+        )
+        .unwrap();
+
+        // Ensure messages were created.
+        assert_eq!(2, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgTransferRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "25".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                    from_address: "bank".to_string(),
+                    to_address: expected_marker.base_account.unwrap().address,
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgTransferRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+
+        match &res.messages[1].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgBurnRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "25".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgBurnRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+
+        // Ensure the coin movement event was emitted.
+        assert_eq!(1, res.events.len());
+        assert_eq!(res.events[0].ty, "coin_movement");
+        assert_eq!(
+            CoinMovement::try_from(&res.events[0]).unwrap(),
+            CoinMovement {
+                from_address: Addr::unchecked("cosmos2contract"),
+                from_address_bank_uuid: None,
+                to_address: Addr::unchecked("bank"),
+                to_address_bank_uuid: Some("bank".into()),
+                block_height: Uint128::new(12345),
+                timestamp: iso8601(env.block.time),
+                amount: Uint128::new(25),
+                denom: "dcc.coin".into(),
+                movement_type: CoinMovementType::Burn,
+            }
+        );
+
+        // Ensure the member supply was decreased.
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.supply, Uint128::new(75));
+    }
+
+    #[test]
+    fn redemption_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Redemptions escrow token in the marker account, so we mock one here.
+        let expected_marker = MarkerAccount {
+            base_account: Some(BaseAccount {
+                address: "dcc.marker".to_string(),
+                pub_key: None,
+                account_number: 1,
+                sequence: 0,
+            }),
+            manager: env.contract.address.to_string(),
+            access_control: vec![],
+            status: MarkerStatus::Active.into(),
+            denom: "dcc.coin".to_string(),
+            supply: "0".to_string(),
+            marker_type: MarkerType::Coin.into(),
+            supply_fixed: false,
+            allow_governance_control: false,
+            allow_forced_transfer: false,
+            required_attributes: vec![],
+        };
+        QueryMarkerRequest::mock_response(
+            &mut deps.querier,
+            QueryMarkerResponse {
+                marker: Some(Any {
+                    type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                    value: expected_marker.encode_to_vec(),
+                }),
+            },
+        );
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Assume the customer has the required attribute and holds token.
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![coin(100, "dcc.coin")]);
+
+        // Redeem token at the member.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Redeem {
+                amount: Uint128::new(60),
+                member_id: "bank".into(),
+                reference: "wire-1".into(),
+                denom: None,
+            },
+        )
+        .unwrap();
+
+        // Ensure the token was escrowed in the marker account.
+        assert_eq!(1, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgTransferRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "60".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                    from_address: "customer".to_string(),
+                    to_address: "dcc.marker".to_string(),
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgTransferRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+        assert_eq!(
+            CoinMovement::try_from(&res.events[0]).unwrap(),
+            CoinMovement {
+                from_address: Addr::unchecked("customer"),
+                from_address_bank_uuid: Some("bank".into()),
+                to_address: Addr::unchecked("dcc.marker"),
+                to_address_bank_uuid: None,
+                block_height: Uint128::new(12345),
+                timestamp: iso8601(env.block.time),
+                amount: Uint128::new(60),
+                denom: "dcc.coin".into(),
+                movement_type: CoinMovementType::Transfer,
+            }
+        );
+
+        // Ensure an open reference cannot be reused.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Redeem {
+                amount: Uint128::new(40),
+                member_id: "bank".into(),
+                reference: "wire-1".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "redemption reference is already open")
+            }
+            _ => panic!("unexpected error: {:?}", err),
+        }
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            ExecuteMsg::Redeem {
+                amount: Uint128::new(40),
+                member_id: "bank".into(),
+                reference: "wire-2".into(),
+                denom: None,
+            },
+        )
+        .unwrap();
+
+        // List the open redemptions in pages.
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRedemptions {
+                member_id: "bank".into(),
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+        let page: Redemptions = from_binary(&bin).unwrap();
+        assert_eq!(page.redemptions.len(), 1);
+        assert_eq!(page.redemptions[0].holder, Addr::unchecked("customer"));
+        assert_eq!(page.redemptions[0].amount, Uint128::new(60));
+        assert_eq!(page.next_key, Some("wire-1".into()));
+
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRedemptions {
+                member_id: "bank".into(),
+                start_after: page.next_key,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+        let page: Redemptions = from_binary(&bin).unwrap();
+        assert_eq!(page.redemptions.len(), 1);
+        assert_eq!(page.redemptions[0].reference, "wire-2");
+        assert_eq!(page.next_key, None);
+
+        // Ensure only the member can settle.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            ExecuteMsg::SettleRedemption {
+                reference: "wire-1".into(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::MemberNotFound { .. }));
+
+        // Settle the first redemption.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::SettleRedemption {
+                reference: "wire-1".into(),
+            },
+        )
+        .unwrap();
+        assert_eq!(1, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgBurnRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "60".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgBurnRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+        assert_eq!(res.attributes[0].value, "burn");
+        assert_eq!(
+            CoinMovement::try_from(&res.events[0]).unwrap(),
+            CoinMovement {
+                from_address: Addr::unchecked("cosmos2contract"),
+                from_address_bank_uuid: None,
+                to_address: Addr::unchecked("dcc.marker"),
+                to_address_bank_uuid: Some("bank".into()),
+                block_height: Uint128::new(12345),
+                timestamp: iso8601(env.block.time),
+                amount: Uint128::new(60),
+                denom: "dcc.coin".into(),
+                movement_type: CoinMovementType::Burn,
+            }
+        );
+
+        // Reject the second redemption.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::RejectRedemption {
+                reference: "wire-2".into(),
+            },
+        )
+        .unwrap();
+        assert_eq!(1, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgWithdrawRequest {
+                    denom: "dcc.coin".to_string(),
+                    administrator: env.contract.address.to_string(),
+                    to_address: "customer".to_string(),
+                    amount: vec![Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "40".to_string(),
+                    }],
+                }
+                .try_into()
+                .unwrap();
+
+                assert_eq!(type_url, "/provenance.marker.v1.MsgWithdrawRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+        let movement = CoinMovement::try_from(&res.events[0]).unwrap();
+        assert_eq!(movement.movement_type, CoinMovementType::Transfer);
+        assert_eq!(movement.from_address, Addr::unchecked("dcc.marker"));
+        assert_eq!(movement.to_address, Addr::unchecked("customer"));
+
+        // Ensure no redemptions are open.
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRedemptions {
+                member_id: "bank".into(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let page: Redemptions = from_binary(&bin).unwrap();
+        assert!(page.redemptions.is_empty());
+
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("bank", &[]),
+            ExecuteMsg::RejectRedemption {
+                reference: "wire-2".into(),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::RedemptionNotFound { reference } => assert_eq!(reference, "wire-2"),
+            _ => panic!("unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn redeem_param_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+
+        // Redemptions escrow token in the marker account, so we mock one here.
+        let expected_marker = MarkerAccount {
+            base_account: Some(BaseAccount {
+                address: "dcc.marker".to_string(),
+                pub_key: None,
+                account_number: 1,
+                sequence: 0,
+            }),
+            manager: env.contract.address.to_string(),
+            access_control: vec![],
+            status: MarkerStatus::Active.into(),
+            denom: "dcc.coin".to_string(),
+            supply: "0".to_string(),
+            marker_type: MarkerType::Coin.into(),
+            supply_fixed: false,
+            allow_governance_control: false,
+            allow_forced_transfer: false,
+            required_attributes: vec![],
+        };
+        QueryMarkerRequest::mock_response(
+            &mut deps.querier,
+            QueryMarkerResponse {
+                marker: Some(Any {
+                    type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                    value: expected_marker.encode_to_vec(),
+                }),
+            },
+        );
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![coin(100, "dcc.coin")]);
+
+        // Try to redeem without the member's kyc attribute.
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: "customer".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+        let redeem = ExecuteMsg::Redeem {
+            amount: Uint128::new(60),
+            member_id: "bank".into(),
+            reference: "wire-1".into(),
+            denom: None,
+        };
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            redeem.clone(),
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MissingKycAttr { addr } => assert_eq!(addr, "customer"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Assume the customer has the required attribute.
+        QueryAttributeRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributeResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Try to redeem from a frozen address.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::FreezeAddress {
+                id: "customer".into(),
+                reason: "investigation".into(),
+            },
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            redeem.clone(),
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::AddressFrozen { addr } => assert_eq!(addr, "customer"),
+            _ => panic!("unexpected execute error"),
+        }
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::UnfreezeAddress {
+                id: "customer".into(),
+            },
+        )
+        .unwrap();
+
+        // Try to redeem while burns are paused.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Pause {
+                scope: PauseScope::Burn,
+                reason: "incident".into(),
+                denom: None,
+            },
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            redeem.clone(),
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Paused { scope } => assert_eq!(scope, PauseScope::Burn),
+            _ => panic!("unexpected execute error"),
+        }
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Unpause {
+                scope: PauseScope::Burn,
+                denom: None,
+            },
+        )
+        .unwrap();
+
+        // Open a redemption and start removing the member.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            redeem,
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("admin", &[]),
+            ExecuteMsg::Remove { id: "bank".into() },
+        )
+        .unwrap();
+
+        // Try to finalize the removal with the redemption still open.
+        let mut env = mock_env();
+        env.block.height = 12445;
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("admin", &[]),
+            ExecuteMsg::FinalizeRemoval { id: "bank".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "member has open redemptions")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn burn_param_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to send additional funds w/ the burn message.
+        let funds = coin(1000, "nhash");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[funds]),
+            ExecuteMsg::Burn {
+                amount: Uint128::new(500),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to send zero amount.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Burn {
+                amount: Uint128::zero(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "invalid burn amount")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to burn as a non-member.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("non.member", &[]),
+            ExecuteMsg::Burn {
+                amount: Uint128::new(500),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MemberNotFound { id } => assert_eq!(id, "non.member"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to burn more than a member holds in their account.
+        let dcc = coin(100, "dcc.coin");
+        deps.querier.mock_querier.update_balance("bank", vec![dcc]);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Burn {
+                amount: Uint128::new(500),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::InsufficientBalance { needed, available } => {
+                assert_eq!(needed, Uint128::new(500));
+                assert_eq!(available, Uint128::new(100));
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn add_kyc_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        let key = "bank".as_bytes();
+        let config_state = members_read(&deps.storage).load(key).unwrap();
+        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb"]);
+
+        // Add new kyc attribute
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::AddKyc {
+                id: None,
+                kyc_attr: "bank2.kyc.pb".into(),
+            },
+        )
+        .unwrap();
+
+        // Ensure we now have the updated kyc attributes.
+        let config_state = members_read(&deps.storage).load(key).unwrap();
+        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb", "bank2.kyc.pb"]);
+
+        // Ensure the kyc attribute was indexed.
+        let member_id = kyc_attr_index_read(&deps.storage)
+            .load(b"bank2.kyc.pb")
+            .unwrap();
+        assert_eq!(member_id, "bank");
+    }
+
+    #[test]
+    fn add_kyc_test_admin() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        let key = "bank".as_bytes();
+        let config_state = members_read(&deps.storage).load(key).unwrap();
+        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb"]);
+
+        // Add new kyc attribute
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddKyc {
+                id: Option::Some("bank".into()),
+                kyc_attr: "bank2.kyc.pb".into(),
+            },
+        )
+        .unwrap();
+
+        // Ensure we now have the updated kyc attribute.
+        let config_state = members_read(&deps.storage).load(key).unwrap();
+        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb", "bank2.kyc.pb"]);
+    }
+
+    #[test]
+    fn add_kyc_param_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to send funds w/ the kyc message.
+        let funds = coin(1000, "nhash");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[funds]),
+            ExecuteMsg::AddKyc {
+                id: Option::None,
+                kyc_attr: "bank2.kyc.pb".into(),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to add an empty name.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddKyc {
+                id: Option::None,
+                kyc_attr: "".into(),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "kyc attribute name is empty")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to add a kyc attribute as a non-admin
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("non.admin", &[]),
+            ExecuteMsg::AddKyc {
+                id: Option::None,
+                kyc_attr: "bank2.kyc.pb".into(),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MemberNotFound { id } => assert_eq!(id, "non.admin"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to add a the kyc attr a second time.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddKyc {
+                id: Some("bank".into()),
+                kyc_attr: "bank.kyc.pb".into(),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::DuplicateKycAttr { attr } => assert_eq!(attr, "bank.kyc.pb"),
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn remove_kyc_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        let key = "bank".as_bytes();
+        let config_state = members_read(&deps.storage).load(key).unwrap();
+        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb"]);
+
+        // Remove kyc attribute
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::RemoveKyc {
+                id: None,
+                kyc_attr: "bank.kyc.pb".into(),
+            },
+        )
+        .unwrap();
+
+        // Ensure we now have the updated kyc attributes.
+        let config_state = members_read(&deps.storage).load(key).unwrap();
+        assert!(config_state.kyc_attrs.is_empty());
+
+        // Ensure the kyc attribute was removed from the index.
+        let member_id = kyc_attr_index_read(&deps.storage)
+            .may_load(b"bank.kyc.pb")
+            .unwrap();
+        assert!(member_id.is_none());
+    }
+
+    #[test]
+    fn remove_kyc_test_admin() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        let key = "bank".as_bytes();
+        let config_state = members_read(&deps.storage).load(key).unwrap();
+        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb"]);
+
+        // Remove new kyc attribute
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveKyc {
+                id: Option::Some("bank".into()),
+                kyc_attr: "bank.kyc.pb".into(),
+            },
+        )
+        .unwrap();
+
+        // Ensure we now have the updated kyc attribute.
+        let config_state = members_read(&deps.storage).load(key).unwrap();
+        assert!(config_state.kyc_attrs.is_empty());
+    }
+
+    #[test]
+    fn remove_kyc_param_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to send funds w/ the kyc message.
+        let funds = coin(1000, "nhash");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[funds]),
+            ExecuteMsg::RemoveKyc {
+                id: Option::None,
+                kyc_attr: "bank.kyc.pb".into(),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to remove an empty name.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveKyc {
+                id: Option::None,
+                kyc_attr: "".into(),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "kyc attribute name is empty")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to remove a kyc attribute as a non-admin
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("non.admin", &[]),
+            ExecuteMsg::RemoveKyc {
+                id: Option::None,
+                kyc_attr: "bank.kyc.pb".into(),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::MemberNotFound { id } => assert_eq!(id, "non.admin"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to remove a kyc attr that does not exist.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveKyc {
+                id: Option::Some("bank".into()),
+                kyc_attr: "bank2.kyc.pb".into(),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "kyc attribute does not exist")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn join_by_vote() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create existing members
+        for id in ["bank1", "bank2", "bank3"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("admin", &[]),
+                ExecuteMsg::Join {
+                    id: id.into(),
+                    name: id.into(),
+                    kyc_attrs: vec![format!("{}.kyc.pb", id)],
+                },
+            )
+            .unwrap();
+        }
+
+        // Give bank1 more weight than the other members.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetMemberWeight {
+                id: "bank1".into(),
+                weight: Uint128::new(2),
+            },
+        )
+        .unwrap();
+
+        // Propose a new member as an existing member.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank2", &[]),
+            ExecuteMsg::ProposeJoin {
+                id: "bank4".into(),
+                name: "bank4".into(),
+                kyc_attrs: vec!["bank4.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Ensure the proposal was created with the default voting window.
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetJoinProposal { id: "bank4".into() },
+        )
+        .unwrap();
+        let proposal: JoinProposalV2 = from_binary(&bin).unwrap();
+        assert_eq!(proposal.proposer, "bank2");
+        assert_eq!(proposal.expires, Uint128::new(17345));
+
+        // Vote yes with a third of the member weight.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank2", &[]),
+            ExecuteMsg::Vote {
+                id: "bank4".into(),
+                choice: VoteChoice::Yes,
+            },
+        )
+        .unwrap();
+
+        // Try to execute the proposal before quorum.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank2", &[]),
+            ExecuteMsg::ExecuteProposal { id: "bank4".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "proposal has not reached quorum")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Vote yes with the weighted member to reach 75% of the member weight.
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::Vote {
+                id: "bank4".into(),
+                choice: VoteChoice::Yes,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.attributes[4].value, "2");
+
+        // Execute the proposal.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteProposal { id: "bank4".into() },
+        )
+        .unwrap();
+
+        // Ensure the member was created and the proposal removed.
+        let member = members_read(&deps.storage).load(b"bank4").unwrap();
+        assert_eq!(member.kyc_attrs, vec!["bank4.kyc.pb".to_string()]);
+        assert_eq!(member.weight, Uint128::one());
+        assert_eq!(
+            kyc_attr_index_read(&deps.storage)
+                .load(b"bank4.kyc.pb")
+                .unwrap(),
+            "bank4"
+        );
+        assert!(join_proposals_read(&deps.storage)
+            .may_load(b"bank4")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn join_by_vote_with_removing_member() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create existing members
+        for id in ["bank1", "bank2", "bank3"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("admin", &[]),
+                ExecuteMsg::Join {
+                    id: id.into(),
+                    name: id.into(),
+                    kyc_attrs: vec![format!("{}.kyc.pb", id)],
+                },
+            )
+            .unwrap();
+        }
+
+        // Propose a new member and vote yes with two thirds of the member weight.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::ProposeJoin {
+                id: "bank4".into(),
+                name: "bank4".into(),
+                kyc_attrs: vec!["bank4.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+        for id in ["bank1", "bank2"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(id, &[]),
+                ExecuteMsg::Vote {
+                    id: "bank4".into(),
+                    choice: VoteChoice::Yes,
+                },
+            )
+            .unwrap();
+        }
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteProposal { id: "bank4".into() },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "proposal has not reached quorum")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Start removing the member that did not vote.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Remove { id: "bank3".into() },
+        )
+        .unwrap();
+
+        // Ensure the member being removed cannot vote.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank3", &[]),
+            ExecuteMsg::Vote {
+                id: "bank4".into(),
+                choice: VoteChoice::Yes,
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::MemberRemoving { id } => assert_eq!(id, "bank3"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Ensure quorum is reached without the weight of the member being removed.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExecuteProposal { id: "bank4".into() },
+        )
+        .unwrap();
+        assert!(members_read(&deps.storage)
+            .may_load(b"bank4")
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn join_by_vote_errors() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create existing members
+        for id in ["bank1", "bank2"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("admin", &[]),
+                ExecuteMsg::Join {
+                    id: id.into(),
+                    name: id.into(),
+                    kyc_attrs: vec![format!("{}.kyc.pb", id)],
+                },
+            )
+            .unwrap();
+        }
+
+        // Try to propose as a non-member.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("notmember", &[]),
+            ExecuteMsg::ProposeJoin {
+                id: "bank3".into(),
+                name: "bank3".into(),
+                kyc_attrs: vec!["bank3.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to propose with a kyc attribute owned by a member.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::ProposeJoin {
+                id: "bank3".into(),
+                name: "bank3".into(),
+                kyc_attrs: vec!["bank1.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::DuplicateKycAttr { attr } => assert_eq!(attr, "bank1.kyc.pb"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::ProposeJoin {
+                id: "bank3".into(),
+                name: "bank3".into(),
+                kyc_attrs: vec!["bank3.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Try to propose the same member again.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::ProposeJoin {
+                id: "bank3".into(),
+                name: "bank3".into(),
+                kyc_attrs: vec!["bank3.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "duplicate proposal")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to vote as the admin, who is not a member.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Vote {
+                id: "bank3".into(),
+                choice: VoteChoice::Yes,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::Vote {
+                id: "bank3".into(),
+                choice: VoteChoice::No,
+            },
+        )
+        .unwrap();
+
+        // Try to vote twice.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::Vote {
+                id: "bank3".into(),
+                choice: VoteChoice::Yes,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "member has already voted")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to expire the proposal while voting is open.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExpireProposal { id: "bank3".into() },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "voting window has not closed")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to vote after the voting window has closed.
+        let mut env = mock_env();
+        env.block.height = 17346;
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank2", &[]),
+            ExecuteMsg::Vote {
+                id: "bank3".into(),
+                choice: VoteChoice::Yes,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "voting window has closed")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Expire the proposal.
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExpireProposal { id: "bank3".into() },
+        )
+        .unwrap();
+
+        // Ensure the proposal was removed.
+        assert!(join_proposals_read(&deps.storage)
+            .may_load(b"bank3")
+            .unwrap()
+            .is_none());
+
+        // Try to vote on an invalid proposal ID.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::Vote {
+                id: "BANK3".into(),
+                choice: VoteChoice::Yes,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Std(StdError::GenericErr { .. })
+        ));
+
+        // Try to propose with a voting window that overflows the block height.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetProposalConfig {
+                quorum_pct: Decimal::percent(51),
+                vote_duration: Uint128::MAX,
+            },
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank1", &[]),
+            ExecuteMsg::ProposeJoin {
+                id: "bank4".into(),
+                name: "bank4".into(),
+                kyc_attrs: vec!["bank4.kyc.pb".into()],
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Std(StdError::Overflow { .. })));
+    }
 
     #[test]
-    fn valid_init() {
+    fn set_proposal_config() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+
+        // Init
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetProposalConfig {
+                quorum_pct: Decimal::percent(51),
+                vote_duration: Uint128::new(100),
+            },
+        )
+        .unwrap();
+
+        // Ensure the proposal config is changed.
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.quorum_pct, Decimal::percent(51));
+        assert_eq!(state.vote_duration, Uint128::new(100));
+
+        // Try to set an invalid quorum percentage.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetProposalConfig {
+                quorum_pct: Decimal::percent(101),
+                vote_duration: Uint128::new(100),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "invalid quorum percentage")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to set the proposal config by user not admin
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("notadmin", &[]),
+            ExecuteMsg::SetProposalConfig {
+                quorum_pct: Decimal::percent(51),
+                vote_duration: Uint128::new(100),
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+    }
+
+    #[test]
+    fn multi_denom_registry() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
         let env = mock_env();
 
         // Init
-        let res = instantiate(
+        instantiate(
             deps.as_mut(),
             env.clone(),
             mock_info("admin", &[]),
@@ -879,35 +7484,35 @@ mod tests {
         )
         .unwrap();
 
-        // Ensure messages were created.
+        // Try to register a denom as non admin.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::AddDenom {
+                denom: "eurdcc.coin".into(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+
+        // Register a second denom, creating its marker.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddDenom {
+                denom: "eurdcc.coin".into(),
+            },
+        )
+        .unwrap();
         assert_eq!(1, res.messages.len());
         match &res.messages[0].msg {
             CosmosMsg::Stargate { type_url, value } => {
-                let expected: Binary = MsgAddFinalizeActivateMarkerRequest {
-                    amount: Some(Coin {
-                        denom: "dcc.coin".to_string(),
-                        amount: "0".to_string(),
-                    }),
-                    manager: env.contract.address.to_string(),
-                    from_address: env.contract.address.to_string(),
-                    marker_type: MarkerType::Restricted.into(),
-                    access_list: vec![
-                        AccessGrant {
-                            address: env.contract.address.to_string(),
-                            permissions: vec![1, 2, 3, 4, 5, 6, 7],
-                        },
-                        AccessGrant {
-                            address: "admin".to_string(),
-                            permissions: vec![6],
-                        },
-                    ],
-                    supply_fixed: false,
-                    allow_governance_control: false,
-                    allow_forced_transfer: false,
-                    required_attributes: vec![],
-                }
-                .try_into()
-                .unwrap();
+                let expected: Binary =
+                    create_marker_request(&env, &Addr::unchecked("admin"), "eurdcc.coin")
+                        .try_into()
+                        .unwrap();
 
                 assert_eq!(
                     type_url,
@@ -918,185 +7523,262 @@ mod tests {
             _ => panic!("unexpected cosmos message"),
         }
 
-        // Read state
-        let config_state = config_read(&deps.storage).load().unwrap();
-
-        // Validate state values
-        assert_eq!(config_state.denom, "dcc.coin");
-
-        let contract_version = get_contract_version(&deps.storage).unwrap();
-
-        assert_eq!(contract_version.contract, CONTRACT_NAME);
-        assert_eq!(contract_version.version, CONTRACT_VERSION);
-    }
+        // Try to register the denom again.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddDenom {
+                denom: "eurdcc.coin".into(),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "denom is already registered")
+            }
+            _ => panic!("unexpected error: {:?}", err),
+        }
 
-    #[test]
-    fn join_test() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
+        // Query the registry.
+        let bin = query(deps.as_ref(), env.clone(), QueryMsg::GetDenoms {}).unwrap();
+        let denoms: Vec<Denom> = from_binary(&bin).unwrap();
+        assert_eq!(
+            denoms,
+            vec![
+                Denom::enabled("dcc.coin".into()),
+                Denom::enabled("eurdcc.coin".into())
+            ]
+        );
 
-        // Init
-        instantiate(
+        // Create join member
+        execute(
             deps.as_mut(),
-            mock_env(),
+            env.clone(),
             mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Mint the second denom.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: Some("eurdcc.coin".into()),
+                reference: None,
             },
         )
         .unwrap();
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgMintRequest {
+                    amount: Some(Coin {
+                        denom: "eurdcc.coin".to_string(),
+                        amount: "100".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                }
+                .try_into()
+                .unwrap();
 
-        // Bank join as member.
+                assert_eq!(type_url, "/provenance.marker.v1.MsgMintRequest");
+                assert_eq!(value, &expected)
+            }
+            _ => panic!("unexpected cosmos message"),
+        }
+
+        // Ensure the supply is tracked per denom.
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.supply, Uint128::zero());
+        let supply = denom_supplies_read(&deps.storage, "eurdcc.coin")
+            .load(b"bank")
+            .unwrap();
+        assert_eq!(supply, Uint128::new(100));
+
+        // Cap the member's supply of the second denom only.
         execute(
             deps.as_mut(),
-            mock_env(),
+            env.clone(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
+            ExecuteMsg::SetMemberLimit {
                 id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+                max_supply: Some(Uint128::new(150)),
+                denom: Some("eurdcc.coin".into()),
             },
         )
         .unwrap();
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.max_supply, None);
 
-        let addr = Addr::unchecked("bank");
-        let key: &[u8] = addr.as_bytes();
-        let member = members_read(&deps.storage).load(key).unwrap();
-
-        assert_eq!(member.id, "bank");
-        assert_eq!(member.joined, Uint128::new(12345));
-        assert_eq!(member.kyc_attrs, vec!["bank.kyc.pb"]);
-        assert_eq!(member.name, "bank");
-    }
-
-    #[test]
-    fn join_invalid_params() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
-
-        // Init
-        instantiate(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetMemberSupply {
+                id: "bank".into(),
+                denom: Some("eurdcc.coin".into()),
             },
         )
         .unwrap();
+        let supply: MemberSupply = from_binary(&bin).unwrap();
+        assert_eq!(
+            supply,
+            MemberSupply {
+                id: Addr::unchecked("bank"),
+                denom: "eurdcc.coin".into(),
+                supply: Uint128::new(100),
+                max_supply: Some(Uint128::new(150)),
+            }
+        );
 
-        // Try to create join member w/ too short name
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "ban".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: Some("eurdcc.coin".into()),
+                reference: None,
             },
         )
         .unwrap_err();
-
-        // Ensure the expected error was returned.
         match err {
             ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "invalid name too short")
+                assert_eq!(msg, "mint exceeds member max supply")
             }
-            _ => panic!("unexpected execute error"),
+            _ => panic!("unexpected error: {:?}", err),
         }
 
-        // Try to create join member w/ empty kyc attr
+        // Try to mint an unregistered denom.
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec![" ".into()],
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: Some("gbpdcc.coin".into()),
+                reference: None,
             },
         )
         .unwrap_err();
+        assert!(matches!(err, ContractError::DenomNotFound { .. }));
 
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "kyc attribute name is empty")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        // Pause minting the second denom only.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Pause {
+                scope: PauseScope::Mint,
+                reason: "reserve audit".into(),
+                denom: Some("eurdcc.coin".into()),
+            },
+        )
+        .unwrap();
 
-        // Try to create join member not admin
         let err = execute(
             deps.as_mut(),
-            mock_env(),
+            env.clone(),
             mock_info("bank", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: Some("eurdcc.coin".into()),
+                reference: None,
             },
         )
         .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Paused {
+                scope: PauseScope::Mint
+            }
+        ));
 
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Unauthorized {} => {}
-            _ => panic!("unexpected execute error"),
-        }
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Unpause {
+                scope: PauseScope::Mint,
+                denom: Some("eurdcc.coin".into()),
+            },
+        )
+        .unwrap();
+
+        // Disable the second denom.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::SetDenomEnabled {
+                denom: "eurdcc.coin".into(),
+                enabled: false,
+            },
+        )
+        .unwrap();
 
-        // Try to send funds w/ the join proposal
-        let funds = coin(1000, "nhash");
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("bank", &[funds]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: Some("eurdcc.coin".into()),
+                reference: None,
             },
         )
         .unwrap_err();
-
-        // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during join")
-            }
-            _ => panic!("unexpected execute error"),
+            ContractError::DenomDisabled { denom } => assert_eq!(denom, "eurdcc.coin"),
+            _ => panic!("unexpected error: {:?}", err),
         }
 
-        // Try to create with same kyc attributes.
+        // Ensure members holding any registered denom cannot be removed.
+        deps.querier
+            .mock_querier
+            .update_balance("bank", vec![coin(100, "eurdcc.coin")]);
+
         let err = execute(
             deps.as_mut(),
-            mock_env(),
+            env,
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec![
-                    "bank.kyc.pb".into(),
-                    "bank2.kyc.pb".into(),
-                    "bank3.kyc.pb".into(),
-                    "bank.kyc.pb".into(),
-                ],
-            },
+            ExecuteMsg::Remove { id: "bank".into() },
         )
         .unwrap_err();
-
-        // Ensure the expected error was returned.
         match err {
             ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "duplicate kyc attributes in args")
+                assert_eq!(msg, "member still holds a token balance")
             }
-            _ => panic!("unexpected execute error"),
+            _ => panic!("unexpected error: {:?}", err),
         }
     }
 
     #[test]
-    fn join_dup_kyc_attribute() {
+    fn pause_unpause() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -1111,140 +7793,177 @@ mod tests {
         )
         .unwrap();
 
-        // Bank join as member.
+        // Create join member
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
             ExecuteMsg::Join {
                 id: "bank".into(),
-                name: "bank1".into(),
-                kyc_attrs: vec!["bank1.kyc.pb".into()],
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
             },
         )
         .unwrap();
 
-        // Try to create a duplicate kyc attribute.
-        let err = execute(
+        // Add an executor
+        execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank2".into(),
-                name: "bank2".into(),
-                kyc_attrs: vec!["bank1.kyc.pb".into()],
+            ExecuteMsg::AddExecutor {
+                id: "executor".into(),
             },
         )
-        .unwrap_err();
-
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "duplicate kyc attribute")
-            }
-            _ => panic!("unexpected execute error"),
-        }
-    }
-
-    #[test]
-    fn join_dup_member_id() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
+        .unwrap();
 
-        // Init
-        instantiate(
+        // Allow the executor to transfer from the bank.
+        execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+            mock_info("bank", &[]),
+            ExecuteMsg::IncreaseAllowance {
+                executor: "executor".into(),
+                amount: Uint128::new(100),
+                denom: None,
             },
         )
         .unwrap();
 
-        // Bank join as member.
+        // Pause transfers
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::Pause {
+                scope: PauseScope::Transfer,
+                reason: "incident".into(),
+                denom: None,
             },
         )
         .unwrap();
 
-        // Try to create a duplicate join proposal.
+        // Ensure the pause state is exposed by contract info.
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::GetContractInfo {}).unwrap();
+        let contract_info: StateV2 = from_binary(&bin).unwrap();
+        assert_eq!(
+            contract_info.paused,
+            vec![PausedScope {
+                scope: PauseScope::Transfer,
+                reason: "incident".into(),
+            }]
+        );
+
+        // Try to transfer while transfers are paused.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank2".into(),
-                kyc_attrs: vec!["bank2.kyc.pb".into()],
+            mock_info("bank", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(100),
+                recipient: "customer".into(),
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "duplicate member")
-            }
+            ContractError::Paused { scope } => assert_eq!(scope, PauseScope::Transfer),
             _ => panic!("unexpected execute error"),
         }
-    }
 
-    #[test]
-    fn remove_test() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
+        // Try to transfer by executor while transfers are paused.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(100),
+                sender: "bank".into(),
+                recipient: "customer".into(),
+                authorization: None,
+                denom: None,
+            },
+        )
+        .unwrap_err();
 
-        // Init
-        instantiate(
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Paused { scope } => assert_eq!(scope, PauseScope::Transfer),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Ensure mints are not paused.
+        execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: None,
             },
         )
         .unwrap();
 
-        // Create join member
+        // Pause mints and unpause transfers.
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::Pause {
+                scope: PauseScope::Mint,
+                reason: "reserve audit".into(),
+                denom: None,
             },
         )
         .unwrap();
-
-        let key = "bank".as_bytes();
-        let mut member = members_read(&deps.storage).may_load(key).unwrap();
-        assert!(member.is_some());
-
-        // Remove member
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Remove { id: "bank".into() },
+            ExecuteMsg::Unpause {
+                scope: PauseScope::Transfer,
+                denom: None,
+            },
         )
         .unwrap();
 
-        member = members_read(&deps.storage).may_load(key).unwrap();
-        assert!(member.is_none());
+        // Try to mint while mints are paused.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(100),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Paused { scope } => assert_eq!(scope, PauseScope::Mint),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Ensure only mints remain paused.
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(
+            state.paused,
+            vec![PausedScope {
+                scope: PauseScope::Mint,
+                reason: "reserve audit".into(),
+            }]
+        );
     }
 
     #[test]
-    fn remove_param_errors() {
+    fn pause_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -1259,72 +7978,69 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
-        execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
-            },
-        )
-        .unwrap();
-
-        // Try to remove a member that doesn't exist.
+        // Try to pause by user not admin
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Remove { id: "bank1".into() },
+            mock_info("notadmin", &[]),
+            ExecuteMsg::Pause {
+                scope: PauseScope::Burn,
+                reason: "incident".into(),
+                denom: None,
+            },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg }) => {
-                assert_eq!(msg, "member does not exist")
-            }
+            ContractError::Unauthorized {} => {}
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to remove a member not as admin.
+        // Try to pause without a reason.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Remove { id: "bank".into() },
+            mock_info("admin", &[]),
+            ExecuteMsg::Pause {
+                scope: PauseScope::Burn,
+                reason: " ".into(),
+                denom: None,
+            },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Unauthorized {} => {}
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "pause reason is empty")
+            }
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to send funds with the cancel message.
-        let funds = coin(1000, "nhash");
+        // Try to unpause a scope that is not paused.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[funds]),
-            ExecuteMsg::Remove { id: "bank".into() },
+            mock_info("admin", &[]),
+            ExecuteMsg::Unpause {
+                scope: PauseScope::Burn,
+                denom: None,
+            },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg }) => {
-                assert_eq!(msg, "no funds should be sent during cancel")
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "scope is not paused")
             }
             _ => panic!("unexpected execute error"),
         }
     }
 
     #[test]
-    fn transfer_test() {
+    fn freeze_address() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
         let env = mock_env();
@@ -1340,28 +8056,31 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
-        execute(
-            deps.as_mut(),
-            env.clone(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
-            },
-        )
-        .unwrap();
+        // Create join members
+        for id in ["bank", "bank2", "bank3"] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("admin", &[]),
+                ExecuteMsg::Join {
+                    id: id.into(),
+                    name: id.into(),
+                    kyc_attrs: vec![format!("{}.kyc.pb", id)],
+                },
+            )
+            .unwrap();
+        }
 
         // Assume the customer has a balance of tokens + the required attribute.
         let dcc = coin(1000, "dcc.coin");
         deps.querier
             .mock_querier
-            .update_balance("customer", vec![dcc]);
+            .update_balance("customer", vec![dcc.clone()]);
+        deps.querier.mock_querier.update_balance("bank2", vec![dcc]);
 
-        QueryAttributeRequest::mock_response(
+        QueryAttributesRequest::mock_response(
             &mut deps.querier,
-            QueryAttributeResponse {
+            QueryAttributesResponse {
                 account: "customer".to_string(),
                 attributes: vec![Attribute {
                     name: "bank.kyc.pb".to_string(),
@@ -1373,14 +8092,132 @@ mod tests {
             },
         );
 
-        // Transfer dcc from the customer to a member bank.
-        let res = execute(
+        // Freeze the customer and the member bank
+        for id in ["customer", "bank"] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("admin", &[]),
+                ExecuteMsg::FreezeAddress {
+                    id: id.into(),
+                    reason: "court order".into(),
+                },
+            )
+            .unwrap();
+        }
+
+        // Ensure the freeze is recorded.
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetFrozenAddress {
+                id: "customer".into(),
+            },
+        )
+        .unwrap();
+        let frozen_address: FrozenAddress = from_binary(&bin).unwrap();
+        assert_eq!(frozen_address.reason, "court order");
+        assert_eq!(frozen_address.frozen, Uint128::new(12345));
+
+        // Try to transfer from the frozen customer.
+        let err = execute(
             deps.as_mut(),
             env.clone(),
             mock_info("customer", &[]),
             ExecuteMsg::Transfer {
                 amount: Uint128::new(500),
-                recipient: "bank".into(),
+                recipient: "bank2".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::AddressFrozen { addr } => assert_eq!(addr, "customer"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to transfer to the frozen customer.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank2", &[]),
+            ExecuteMsg::Transfer {
+                amount: Uint128::new(500),
+                recipient: "customer".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::AddressFrozen { addr } => assert_eq!(addr, "customer"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to mint to the frozen member bank.
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bank", &[]),
+            ExecuteMsg::Mint {
+                amount: Uint128::new(500),
+                address: None,
+                denom: None,
+                reference: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::AddressFrozen { addr } => assert_eq!(addr, "bank"),
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to force transfer tokens to a member being removed.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Remove { id: "bank3".into() },
+        )
+        .unwrap();
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::ForceTransfer {
+                amount: Uint128::new(500),
+                sender: "customer".into(),
+                recipient: "bank3".into(),
+                reason: "clawback".into(),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "recipient member is being removed")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Force transfer tokens from the frozen customer to a member bank.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::ForceTransfer {
+                amount: Uint128::new(500),
+                sender: "customer".into(),
+                recipient: "bank2".into(),
+                reason: "clawback".into(),
+                denom: None,
             },
         )
         .unwrap();
@@ -1396,7 +8233,7 @@ mod tests {
                     }),
                     administrator: env.contract.address.to_string(),
                     from_address: "customer".to_string(),
-                    to_address: "bank".to_string(),
+                    to_address: "bank2".to_string(),
                 }
                 .try_into()
                 .unwrap();
@@ -1406,81 +8243,59 @@ mod tests {
             }
             _ => panic!("unexpected cosmos message"),
         }
-    }
-
-    #[test]
-    fn transfer_param_errors() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
 
-        // Init
-        instantiate(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+        // Ensure the force transfer and its reason were recorded.
+        let bin = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetForceTransfers {
+                id: "customer".into(),
+                start_after: None,
+                limit: None,
             },
         )
         .unwrap();
+        let page: ForceTransfers = from_binary(&bin).unwrap();
+        assert_eq!(
+            page.force_transfers,
+            vec![ForceTransfer {
+                id: Uint128::one(),
+                address: Addr::unchecked("customer"),
+                recipient: Addr::unchecked("bank2"),
+                amount: Uint128::new(500),
+                denom: "dcc.coin".into(),
+                reason: "clawback".into(),
+                height: Uint128::new(12345),
+            }]
+        );
+        assert_eq!(page.next_key, None);
 
-        // Create join member
+        // Unfreeze the customer and transfer.
         execute(
             deps.as_mut(),
-            mock_env(),
+            env.clone(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::UnfreezeAddress {
+                id: "customer".into(),
             },
         )
         .unwrap();
 
-        // Try to transfer zero dcc from the customer to a member bank.
-        let err = execute(
+        execute(
             deps.as_mut(),
-            mock_env(),
+            env,
             mock_info("customer", &[]),
-            ExecuteMsg::Transfer {
-                amount: Uint128::zero(),
-                recipient: "bank".into(),
-            },
-        )
-        .unwrap_err();
-
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "invalid transfer amount")
-            }
-            _ => panic!("unexpected execute error"),
-        }
-
-        // Try to send additional funds during transfer.
-        let funds = coin(1000, "nhash");
-        let err = execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("customer", &[funds]),
             ExecuteMsg::Transfer {
                 amount: Uint128::new(500),
-                recipient: "bank".into(),
+                recipient: "bank2".into(),
+                denom: None,
             },
         )
-        .unwrap_err();
-
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "bank sends are not allowed in transfer")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        .unwrap();
     }
 
     #[test]
-    fn transfer_insufficient_funds() {
+    fn freeze_address_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -1493,44 +8308,33 @@ mod tests {
                 denom: "dcc.coin".into(),
             },
         )
-        .unwrap();
-
-        // Create join member
-        execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
-            },
-        )
-        .unwrap();
-
-        // Assume the customer has the required attribute, but no tokens.
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: "customer".to_string(),
-                attributes: vec![Attribute {
-                    name: "bank.kyc.pb".to_string(),
-                    value: "ok".as_bytes().to_vec(),
-                    attribute_type: AttributeType::String.into(),
-                    address: "".to_string(),
-                }],
-                pagination: None,
+        .unwrap();
+
+        // Try to freeze by user not admin
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("notadmin", &[]),
+            ExecuteMsg::FreezeAddress {
+                id: "customer".into(),
+                reason: "court order".into(),
             },
-        );
+        )
+        .unwrap_err();
 
-        // Try to transfer dcc from the customer to the member bank.
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to unfreeze an address that is not frozen.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("customer", &[]),
-            ExecuteMsg::Transfer {
-                amount: Uint128::new(500),
-                recipient: "bank".into(),
+            mock_info("admin", &[]),
+            ExecuteMsg::UnfreezeAddress {
+                id: "customer".into(),
             },
         )
         .unwrap_err();
@@ -1538,78 +8342,81 @@ mod tests {
         // Ensure the expected error was returned.
         match err {
             ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "insufficient token balance in transfer")
+                assert_eq!(msg, "address is not frozen")
             }
             _ => panic!("unexpected execute error"),
         }
-    }
-
-    #[test]
-    fn transfer_without_from_attribute() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
 
-        // Init
-        instantiate(
+        // Try to force transfer from an address that is not frozen.
+        let err = execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+            ExecuteMsg::ForceTransfer {
+                amount: Uint128::new(500),
+                sender: "customer".into(),
+                recipient: "bank".into(),
+                reason: "clawback".into(),
+                denom: None,
             },
         )
-        .unwrap();
+        .unwrap_err();
 
-        // Create join member
-        execute(
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "sender address is not frozen")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to force transfer without a reason.
+        let err = execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::ForceTransfer {
+                amount: Uint128::new(500),
+                sender: "customer".into(),
+                recipient: "bank".into(),
+                reason: "".into(),
+                denom: None,
             },
         )
-        .unwrap();
+        .unwrap_err();
 
-        // Assume the customer has a balance of dcc, but does NOT have the required attribute.
-        let dcc = coin(1000, "dcc.coin");
-        deps.querier
-            .mock_querier
-            .update_balance("customer", vec![dcc]);
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: "customer".to_string(),
-                attributes: vec![],
-                pagination: None,
-            },
-        );
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "force transfer reason is empty")
+            }
+            _ => panic!("unexpected execute error"),
+        }
 
-        // Try to transfer dcc from the customer to a member bank.
+        // Try to force transfer by user not admin
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("customer", &[]),
-            ExecuteMsg::Transfer {
+            mock_info("notadmin", &[]),
+            ExecuteMsg::ForceTransfer {
                 amount: Uint128::new(500),
+                sender: "customer".into(),
                 recipient: "bank".into(),
+                reason: "clawback".into(),
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no kyc attributes found for customer")
-            }
+            ContractError::Unauthorized {} => {}
             _ => panic!("unexpected execute error"),
         }
     }
 
     #[test]
-    fn transfer_without_to_attribute() {
+    fn propose_accept_admin() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -1624,80 +8431,56 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::ProposeAdmin {
+                id: "newadmin".into(),
+                expires_at: Some(Uint128::new(12400)),
             },
         )
         .unwrap();
 
-        // Assume the customer has a balance of tokens + the required attribute.
-        let dcc = coin(1000, "dcc.coin");
-        deps.querier
-            .mock_querier
-            .update_balance("customer", vec![dcc]);
-
-        // TODO - fix test since mock response returns same result no matter the input
-        // QueryAttributeRequest::mock_response(
-        //     &mut deps.querier,
-        //     QueryAttributeResponse {
-        //         account: "customer".to_string(),
-        //         attributes: vec![Attribute {
-        //             name: "bank.kyc.pb".to_string(),
-        //             value: "ok".as_bytes().to_vec(),
-        //             attribute_type: AttributeType::String.into(),
-        //             address: "".to_string(),
-        //         }],
-        //         pagination: None,
-        //     },
-        // );
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: "customer".to_string(),
-                attributes: vec![],
-                pagination: None,
-            },
+        // Ensure admin is unchanged and the proposal is pending.
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.admin, "admin");
+        assert_eq!(
+            state.pending_admin,
+            Some(PendingAdmin {
+                address: Addr::unchecked("newadmin"),
+                expires_at: Some(Uint128::new(12400)),
+            })
         );
 
-        // Transfer dcc from the customer to a member bank.
-        let err = execute(
+        // Ensure the pending admin is exposed by contract info.
+        let bin = query(deps.as_ref(), mock_env(), QueryMsg::GetContractInfo {}).unwrap();
+        let contract_info: StateV2 = from_binary(&bin).unwrap();
+        assert_eq!(contract_info.pending_admin, state.pending_admin);
+
+        execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("customer", &[]),
-            ExecuteMsg::Transfer {
-                amount: Uint128::new(500),
-                recipient: "customer2".into(),
-            },
+            mock_info("newadmin", &[]),
+            ExecuteMsg::AcceptAdmin {},
         )
-        .unwrap_err();
+        .unwrap();
 
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                // TODO - reenable after mock response return is fixed
-                // assert_eq!(msg, "no kyc attributes found for customer2")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        // Ensure admin is changed.
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.admin, "newadmin");
+        assert_eq!(state.pending_admin, None);
     }
 
     #[test]
-    fn mint_test() {
+    fn cancel_admin_proposal() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
-        let env = mock_env();
 
         // Init
         instantiate(
             deps.as_mut(),
-            env.clone(),
+            mock_env(),
             mock_info("admin", &[]),
             InitMsg {
                 denom: "dcc.coin".into(),
@@ -1705,74 +8488,50 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
         execute(
             deps.as_mut(),
-            env.clone(),
+            mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::ProposeAdmin {
+                id: "newadmin".into(),
+                expires_at: None,
             },
         )
         .unwrap();
 
-        // Mint reserve tokens and withdraw them.
-        let res = execute(
+        execute(
             deps.as_mut(),
-            env.clone(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Mint {
-                amount: Uint128::new(100),
-                address: None,
-            },
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::CancelAdminProposal {},
         )
         .unwrap();
 
-        // Ensure messages were created.
-        assert_eq!(2, res.messages.len());
-        match &res.messages[0].msg {
-            CosmosMsg::Stargate { type_url, value } => {
-                let expected: Binary = MsgMintRequest {
-                    amount: Some(Coin {
-                        denom: "dcc.coin".to_string(),
-                        amount: "100".to_string(),
-                    }),
-                    administrator: env.contract.address.to_string(),
-                }
-                .try_into()
-                .unwrap();
-
-                assert_eq!(type_url, "/provenance.marker.v1.MsgMintRequest");
-                assert_eq!(value, &expected)
-            }
-            _ => panic!("unexpected cosmos message"),
-        }
+        // Ensure the proposal was removed.
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.admin, "admin");
+        assert_eq!(state.pending_admin, None);
 
-        match &res.messages[1].msg {
-            CosmosMsg::Stargate { type_url, value } => {
-                let expected: Binary = MsgWithdrawRequest {
-                    denom: "dcc.coin".to_string(),
-                    administrator: env.contract.address.to_string(),
-                    to_address: "bank".to_string(),
-                    amount: vec![Coin {
-                        denom: "dcc.coin".to_string(),
-                        amount: "100".to_string(),
-                    }],
-                }
-                .try_into()
-                .unwrap();
+        // Try to accept the canceled proposal.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("newadmin", &[]),
+            ExecuteMsg::AcceptAdmin {},
+        )
+        .unwrap_err();
 
-                assert_eq!(type_url, "/provenance.marker.v1.MsgWithdrawRequest");
-                assert_eq!(value, &expected)
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "no pending admin proposal")
             }
-            _ => panic!("unexpected cosmos message"),
+            _ => panic!("unexpected execute error"),
         }
     }
 
     #[test]
-    fn mint_param_errors() {
+    fn propose_admin_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -1787,91 +8546,110 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
-        execute(
+        // Try to send funds w/ the propose admin message.
+        let funds = coin(1000, "nhash");
+        let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            mock_info("admin", &[funds]),
+            ExecuteMsg::ProposeAdmin {
+                id: "newadmin".into(),
+                expires_at: None,
             },
         )
-        .unwrap();
+        .unwrap_err();
 
-        // Try to mint zero reserve tokens
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to propose admin by user not admin
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Mint {
-                amount: Uint128::zero(),
-                address: None,
+            mock_info("notadmin", &[]),
+            ExecuteMsg::ProposeAdmin {
+                id: "newadmin".into(),
+                expires_at: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg }) => {
-                assert_eq!(msg, "invalid mint amount")
-            }
+            ContractError::Unauthorized {} => {}
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to send funds with the mint message
-        let funds = coin(1000, "nhash");
+        // Try to propose the existing admin.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("bank", &[funds]),
-            ExecuteMsg::Mint {
-                amount: Uint128::new(1000),
-                address: None,
+            mock_info("admin", &[]),
+            ExecuteMsg::ProposeAdmin {
+                id: "admin".into(),
+                expires_at: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "admin address is unchanged")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to propose an expiry in the past.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::ProposeAdmin {
+                id: "newadmin".into(),
+                expires_at: Some(Uint128::new(12345)),
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg }) => {
-                assert_eq!(msg, "no funds should be sent during mint")
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "admin proposal expiry must be in the future")
             }
             _ => panic!("unexpected execute error"),
         }
 
-        // Call mint as a non-member
+        // Try to cancel when there is no proposal.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("non.member", &[]),
-            ExecuteMsg::Mint {
-                amount: Uint128::new(1000),
-                address: None,
-            },
+            mock_info("admin", &[]),
+            ExecuteMsg::CancelAdminProposal {},
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::NotFound { kind }) => {
-                assert_eq!(kind, "dcc::member::MemberV2");
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "no pending admin proposal")
             }
             _ => panic!("unexpected execute error"),
         }
     }
 
     #[test]
-    fn mint_withdraw_test() {
+    fn accept_admin_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
-        let env = mock_env();
 
         // Init
         instantiate(
             deps.as_mut(),
-            env.clone(),
+            mock_env(),
             mock_info("admin", &[]),
             InitMsg {
                 denom: "dcc.coin".into(),
@@ -1879,89 +8657,73 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
         execute(
             deps.as_mut(),
-            env.clone(),
+            mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::ProposeAdmin {
+                id: "newadmin".into(),
+                expires_at: Some(Uint128::new(12400)),
             },
         )
         .unwrap();
 
-        // Assume the customer has the required attribute.
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: "customer".to_string(),
-                attributes: vec![Attribute {
-                    name: "bank.kyc.pb".to_string(),
-                    value: "ok".as_bytes().to_vec(),
-                    attribute_type: AttributeType::String.into(),
-                    address: "".to_string(),
-                }],
-                pagination: None,
-            },
-        );
-
-        // Mint reserve tokens and withdraw dcc to a customer address.
-        let res = execute(
+        // Try to accept by user not the proposed admin
+        let err = execute(
             deps.as_mut(),
-            env.clone(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Mint {
-                amount: Uint128::new(100),
-                address: Some("customer".into()),
-            },
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AcceptAdmin {},
         )
-        .unwrap();
+        .unwrap_err();
 
-        // Ensure messages were created.
-        assert_eq!(2, res.messages.len());
-        match &res.messages[0].msg {
-            CosmosMsg::Stargate { type_url, value } => {
-                let expected: Binary = MsgMintRequest {
-                    amount: Some(Coin {
-                        denom: "dcc.coin".to_string(),
-                        amount: "100".to_string(),
-                    }),
-                    administrator: env.contract.address.to_string(),
-                }
-                .try_into()
-                .unwrap();
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
 
-                assert_eq!(type_url, "/provenance.marker.v1.MsgMintRequest");
-                assert_eq!(value, &expected)
-            }
-            _ => panic!("unexpected cosmos message"),
+        // Try to cancel by user not admin
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("newadmin", &[]),
+            ExecuteMsg::CancelAdminProposal {},
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
         }
 
-        match &res.messages[1].msg {
-            CosmosMsg::Stargate { type_url, value } => {
-                let expected: Binary = MsgWithdrawRequest {
-                    denom: "dcc.coin".to_string(),
-                    administrator: env.contract.address.to_string(),
-                    to_address: "customer".to_string(),
-                    amount: vec![Coin {
-                        denom: "dcc.coin".to_string(),
-                        amount: "100".to_string(),
-                    }],
-                }
-                .try_into()
-                .unwrap();
+        // Try to accept after the proposal expired.
+        let mut env = mock_env();
+        env.block.height = 12401;
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("newadmin", &[]),
+            ExecuteMsg::AcceptAdmin {},
+        )
+        .unwrap_err();
 
-                assert_eq!(type_url, "/provenance.marker.v1.MsgWithdrawRequest");
-                assert_eq!(value, &expected)
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "admin proposal has expired")
             }
-            _ => panic!("unexpected cosmos message"),
+            _ => panic!("unexpected execute error"),
         }
+
+        // Ensure admin is unchanged.
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.admin, "admin");
     }
 
     #[test]
-    fn mint_withdraw_not_member_customer() {
+    fn add_executor() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -1976,80 +8738,23 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member 1
-        execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank1".into(),
-                name: "bank1".into(),
-                kyc_attrs: vec!["bank1.kyc.pb".into()],
-            },
-        )
-        .unwrap();
-
-        // Create join member 2
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank2".into(),
-                name: "bank2".into(),
-                kyc_attrs: vec!["bank2.kyc.pb".into()],
+            ExecuteMsg::AddExecutor {
+                id: "executor".into(),
             },
         )
         .unwrap();
 
-        // TODO - fix mock response so it is only returnable for checking bank2 and not bank1
-        // Assume the customer has the required attribute.
-        // QueryAttributeRequest::mock_response(
-        //     &mut deps.querier,
-        //     QueryAttributeResponse {
-        //         account: "customer".to_string(),
-        //         attributes: vec![Attribute {
-        //             name: "bank2.kyc.pb".to_string(),
-        //             value: "ok".as_bytes().to_vec(),
-        //             attribute_type: AttributeType::String.into(),
-        //             address: "".to_string(),
-        //         }],
-        //         pagination: None,
-        //     },
-        // );
-
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: "customer".to_string(),
-                attributes: vec![],
-                pagination: None,
-            },
-        );
-
-        // Mint reserve tokens and withdraw dcc to a customer address.
-        let err = execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("bank1", &[]),
-            ExecuteMsg::Mint {
-                amount: Uint128::new(100),
-                address: Some("customer".into()),
-            },
-        )
-        .unwrap_err();
-
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg }) => {
-                assert_eq!(msg, "no kyc attributes found for customer")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        // Ensure admin is changed.
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.executors, vec!["executor"]);
     }
 
     #[test]
-    fn mint_withdraw_no_attribute() {
+    fn add_executor_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -2064,91 +8769,81 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
+        // Add single executor
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::AddExecutor {
+                id: "executor".into(),
             },
         )
         .unwrap();
 
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: "customer".to_string(),
-                attributes: vec![],
-                pagination: None,
+        // Try to send funds w/ the kyc message.
+        let funds = coin(1000, "nhash");
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[funds]),
+            ExecuteMsg::AddExecutor {
+                id: "executor2".into(),
             },
-        );
+        )
+        .unwrap_err();
 
-        // Mint reserve tokens and withdraw dcc to a customer address.
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to add executor by user not admin
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Mint {
-                amount: Uint128::new(100),
-                address: Some("customer".into()),
+            mock_info("notadmin", &[]),
+            ExecuteMsg::AddExecutor {
+                id: "executor2".into(),
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg }) => {
-                assert_eq!(msg, "no kyc attributes found for customer")
-            }
+            ContractError::Unauthorized {} => {}
             _ => panic!("unexpected execute error"),
         }
-    }
-
-    #[test]
-    fn burn_test() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
-        let env = mock_env();
 
-        // Burn needs to query the marker address, so we mock one here.
-        let expected_marker = MarkerAccount {
-            base_account: Some(BaseAccount {
-                address: "dcc.marker".to_string(),
-                pub_key: None,
-                account_number: 1,
-                sequence: 0,
-            }),
-            manager: env.contract.address.to_string(),
-            access_control: vec![AccessGrant {
-                address: "tp18vd8fpwxzck93qlwghaj6arh4p7c5n89x8kskz".to_string(),
-                permissions: vec![1, 2, 3, 4, 5, 6, 7],
-            }],
-            status: MarkerStatus::Active.into(),
-            denom: "dcc.coin".to_string(),
-            supply: "0".to_string(),
-            marker_type: MarkerType::Coin.into(),
-            supply_fixed: false,
-            allow_governance_control: false,
-            allow_forced_transfer: false,
-            required_attributes: vec![],
-        };
+        // Try to add executor already exists.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddExecutor {
+                id: "executor".into(),
+            },
+        )
+        .unwrap_err();
 
-        let mock_marker_response = QueryMarkerResponse {
-            marker: Some(Any {
-                type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
-                value: expected_marker.encode_to_vec(),
-            }),
-        };
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "executor already exists")
+            }
+            _ => panic!("unexpected execute error"),
+        }
+    }
 
-        QueryMarkerRequest::mock_response(&mut deps.querier, mock_marker_response);
+    #[test]
+    fn remove_executor() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
 
         // Init
         instantiate(
             deps.as_mut(),
-            env.clone(),
+            mock_env(),
             mock_info("admin", &[]),
             InitMsg {
                 denom: "dcc.coin".into(),
@@ -2156,91 +8851,44 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
+        // Add executors
         execute(
             deps.as_mut(),
-            env.clone(),
+            mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::AddExecutor {
+                id: "executor1".into(),
             },
         )
         .unwrap();
 
-        // Mint reserve tokens.
         execute(
             deps.as_mut(),
-            env.clone(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Mint {
-                amount: Uint128::new(100),
-                address: None,
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddExecutor {
+                id: "executor2".into(),
             },
         )
         .unwrap();
 
-        // Simulate balance update due to mint.
-        let minted = coin(100, "dcc.coin");
-        deps.querier
-            .mock_querier
-            .update_balance("bank", vec![minted]);
-
-        // Burn reserve tokens.
-        let res = execute(
+        execute(
             deps.as_mut(),
-            env.clone(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Burn {
-                amount: Uint128::new(25),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveExecutor {
+                id: "executor1".into(),
             },
         )
         .unwrap();
 
-        // Ensure messages were created.
-        assert_eq!(2, res.messages.len());
-        match &res.messages[0].msg {
-            CosmosMsg::Stargate { type_url, value } => {
-                let expected: Binary = MsgTransferRequest {
-                    amount: Some(Coin {
-                        denom: "dcc.coin".to_string(),
-                        amount: "25".to_string(),
-                    }),
-                    administrator: env.contract.address.to_string(),
-                    from_address: "bank".to_string(),
-                    to_address: expected_marker.base_account.unwrap().address,
-                }
-                .try_into()
-                .unwrap();
-
-                assert_eq!(type_url, "/provenance.marker.v1.MsgTransferRequest");
-                assert_eq!(value, &expected)
-            }
-            _ => panic!("unexpected cosmos message"),
-        }
-
-        match &res.messages[1].msg {
-            CosmosMsg::Stargate { type_url, value } => {
-                let expected: Binary = MsgBurnRequest {
-                    amount: Some(Coin {
-                        denom: "dcc.coin".to_string(),
-                        amount: "25".to_string(),
-                    }),
-                    administrator: env.contract.address.to_string(),
-                }
-                .try_into()
-                .unwrap();
-
-                assert_eq!(type_url, "/provenance.marker.v1.MsgBurnRequest");
-                assert_eq!(value, &expected)
-            }
-            _ => panic!("unexpected cosmos message"),
-        }
+        // Ensure admin is changed.
+        let state = config_read(&deps.storage).load().unwrap();
+        assert_eq!(state.executors, vec!["executor2"]);
     }
 
     #[test]
-    fn burn_param_errors() {
+    fn remove_executor_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -2255,102 +8903,196 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
+        // Add executors
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::AddExecutor {
+                id: "executor1".into(),
             },
         )
         .unwrap();
 
-        // Try to send additional funds w/ the burn message.
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddExecutor {
+                id: "executor2".into(),
+            },
+        )
+        .unwrap();
+
+        // Try to send funds w/ the kyc message.
         let funds = coin(1000, "nhash");
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("bank", &[funds]),
-            ExecuteMsg::Burn {
-                amount: Uint128::new(500),
+            mock_info("admin", &[funds]),
+            ExecuteMsg::RemoveExecutor {
+                id: "executor2".into(),
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during burn")
-            }
+            ContractError::FundsNotAllowed {} => {}
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to send zero amount.
+        // Try to remove executor by user not admin
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Burn {
-                amount: Uint128::zero(),
+            mock_info("notadmin", &[]),
+            ExecuteMsg::RemoveExecutor {
+                id: "executor2".into(),
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "invalid burn amount")
-            }
+            ContractError::Unauthorized {} => {}
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to burn as a non-member.
+        // Try to remove executor does not exist.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("non.member", &[]),
-            ExecuteMsg::Burn {
-                amount: Uint128::new(500),
+            mock_info("admin", &[]),
+            ExecuteMsg::RemoveExecutor {
+                id: "executor".into(),
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::NotFound { kind }) => {
-                assert_eq!(kind, "dcc::member::MemberV2");
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "executor does not exist")
             }
             _ => panic!("unexpected execute error"),
         }
+    }
 
-        // Try to burn more than a member holds in their account.
-        let dcc = coin(100, "dcc.coin");
-        deps.querier.mock_querier.update_balance("bank", vec![dcc]);
+    #[test]
+    fn executor_transfer_test() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
 
-        let err = execute(
+        // Init
+        instantiate(
             deps.as_mut(),
-            mock_env(),
-            mock_info("bank", &[]),
-            ExecuteMsg::Burn {
+            env.clone(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
+            },
+        )
+        .unwrap();
+
+        // Create join member
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
+            },
+        )
+        .unwrap();
+
+        // Add executor
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddExecutor {
+                id: "executor".into(),
+            },
+        )
+        .unwrap();
+
+        // Allow the executor to transfer from the customer.
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("customer", &[]),
+            ExecuteMsg::IncreaseAllowance {
+                executor: "executor".into(),
                 amount: Uint128::new(500),
+                denom: None,
             },
         )
-        .unwrap_err();
+        .unwrap();
+
+        // Assume the customer has a balance of tokens + the required attribute.
+        let dcc = coin(1000, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![dcc]);
+
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Transfer dcc from the customer to a member bank.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(500),
+                sender: "customer".into(),
+                recipient: "bank".into(),
+                authorization: None,
+                denom: None,
+            },
+        )
+        .unwrap();
+
+        // Ensure message was created.
+        assert_eq!(1, res.messages.len());
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                let expected: Binary = MsgTransferRequest {
+                    amount: Some(Coin {
+                        denom: "dcc.coin".to_string(),
+                        amount: "500".to_string(),
+                    }),
+                    administrator: env.contract.address.to_string(),
+                    from_address: "customer".to_string(),
+                    to_address: "bank".to_string(),
+                }
+                .try_into()
+                .unwrap();
 
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "insufficient token balance in burn")
+                assert_eq!(type_url, "/provenance.marker.v1.MsgTransferRequest");
+                assert_eq!(value, &expected)
             }
-            _ => panic!("unexpected execute error"),
+            _ => panic!("unexpected cosmos message"),
         }
     }
 
     #[test]
-    fn add_kyc_test() {
+    fn executor_allowance() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -2365,99 +9107,150 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
+        // Add executor
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            ExecuteMsg::AddExecutor {
+                id: "executor".into(),
             },
         )
         .unwrap();
 
-        let key = "bank".as_bytes();
-        let config_state = members_read(&deps.storage).load(key).unwrap();
-        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb"]);
-
-        // Add new kyc attribute
+        // Allow the executor to transfer from the customer in two steps.
         execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("bank", &[]),
-            ExecuteMsg::AddKyc {
-                id: None,
-                kyc_attr: "bank2.kyc.pb".into(),
+            mock_info("customer", &[]),
+            ExecuteMsg::IncreaseAllowance {
+                executor: "executor".into(),
+                amount: Uint128::new(300),
+                denom: None,
             },
         )
         .unwrap();
-
-        // Ensure we now have the updated kyc attributes.
-        let config_state = members_read(&deps.storage).load(key).unwrap();
-        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb", "bank2.kyc.pb"]);
-    }
-
-    #[test]
-    fn add_kyc_test_admin() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
-
-        // Init
-        instantiate(
+        let res = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+            mock_info("customer", &[]),
+            ExecuteMsg::IncreaseAllowance {
+                executor: "executor".into(),
+                amount: Uint128::new(200),
+                denom: None,
             },
         )
         .unwrap();
+        assert_eq!(res.attributes[3].value, "500");
 
-        // Create join member
+        // Decrease the allowance.
         execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            mock_info("customer", &[]),
+            ExecuteMsg::DecreaseAllowance {
+                executor: "executor".into(),
+                amount: Uint128::new(100),
+                denom: None,
             },
         )
         .unwrap();
 
-        let key = "bank".as_bytes();
-        let config_state = members_read(&deps.storage).load(key).unwrap();
-        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb"]);
+        // Ensure the allowance was updated.
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetAllowance {
+                holder: "customer".into(),
+                executor: "executor".into(),
+                denom: None,
+            },
+        )
+        .unwrap();
+        let allowance: Allowance = from_binary(&bin).unwrap();
+        assert_eq!(allowance.amount, Uint128::new(400));
 
-        // Add new kyc attribute
+        // Try to transfer more than the allowance.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(500),
+                sender: "customer".into(),
+                recipient: "bank".into(),
+                authorization: None,
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::InsufficientAllowance { needed, available } => {
+                assert_eq!(needed, Uint128::new(500));
+                assert_eq!(available, Uint128::new(400));
+            }
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Decreasing below zero revokes the allowance.
         execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::AddKyc {
-                id: Option::Some("bank".into()),
-                kyc_attr: "bank2.kyc.pb".into(),
+            mock_info("customer", &[]),
+            ExecuteMsg::DecreaseAllowance {
+                executor: "executor".into(),
+                amount: Uint128::new(1000),
+                denom: None,
             },
         )
         .unwrap();
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetAllowance {
+                holder: "customer".into(),
+                executor: "executor".into(),
+                denom: None,
+            },
+        )
+        .unwrap();
+        let allowance: Allowance = from_binary(&bin).unwrap();
+        assert_eq!(allowance.amount, Uint128::zero());
 
-        // Ensure we now have the updated kyc attribute.
-        let config_state = members_read(&deps.storage).load(key).unwrap();
-        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb", "bank2.kyc.pb"]);
+        // Try to allow an unknown executor.
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("customer", &[]),
+            ExecuteMsg::IncreaseAllowance {
+                executor: "unknown".into(),
+                amount: Uint128::new(100),
+                denom: None,
+            },
+        )
+        .unwrap_err();
+
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Std(StdError::GenericErr { msg, .. }) => {
+                assert_eq!(msg, "executor does not exist")
+            }
+            _ => panic!("unexpected execute error"),
+        }
     }
 
     #[test]
-    fn add_kyc_param_errors() {
+    fn executor_transfer_authorization() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
 
         // Init
         instantiate(
             deps.as_mut(),
-            mock_env(),
+            env.clone(),
             mock_info("admin", &[]),
             InitMsg {
                 denom: "dcc.coin".into(),
@@ -2468,7 +9261,7 @@ mod tests {
         // Create join member
         execute(
             deps.as_mut(),
-            mock_env(),
+            env.clone(),
             mock_info("admin", &[]),
             ExecuteMsg::Join {
                 id: "bank".into(),
@@ -2478,138 +9271,191 @@ mod tests {
         )
         .unwrap();
 
-        // Try to send funds w/ the kyc message.
-        let funds = coin(1000, "nhash");
-        let err = execute(
+        // Add executor
+        execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[funds]),
-            ExecuteMsg::AddKyc {
-                id: Option::None,
-                kyc_attr: "bank2.kyc.pb".into(),
+            env.clone(),
+            mock_info("admin", &[]),
+            ExecuteMsg::AddExecutor {
+                id: "executor".into(),
             },
         )
-        .unwrap_err();
+        .unwrap();
 
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during kyc add")
-            }
-            _ => panic!("unexpected execute error"),
-        }
+        // Derive the customer address from a signing key.
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+        let pub_key = Binary::from(signing_key.verifying_key().to_bytes().as_slice());
+        let customer = pub_key_address(&pub_key, "tp").unwrap();
 
-        // Try to add an empty name.
+        // Assume the customer has a balance of tokens + the required attribute.
+        let dcc = coin(1000, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance(customer.clone(), vec![dcc]);
+
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: customer.clone(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Sign an authorization of the transfer as the customer.
+        let payload = AuthorizationPayload {
+            contract: env.contract.address.clone(),
+            executor: Addr::unchecked("executor"),
+            sender: Addr::unchecked(&customer),
+            recipient: Addr::unchecked("bank"),
+            amount: Uint128::new(500),
+            nonce: Uint128::new(1),
+            expires: Uint128::from(env.block.height + 10),
+            denom: None,
+        };
+        let signature: Signature = signing_key.sign(&to_vec(&payload).unwrap());
+        let authorization = TransferAuthorization {
+            pub_key: pub_key.clone(),
+            signature: Binary::from(signature.as_ref()),
+            nonce: payload.nonce,
+            expires: payload.expires,
+        };
+
+        // Try to transfer a different amount than was signed.
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::AddKyc {
-                id: Option::None,
-                kyc_attr: "".into(),
+            env.clone(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(600),
+                sender: customer.clone(),
+                recipient: "bank".into(),
+                authorization: Some(authorization.clone()),
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "kyc attribute name is empty")
+            ContractError::InvalidAuthorization { reason } => {
+                assert_eq!(reason, "invalid signature")
             }
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to add a kyc attribute as a non-admin
+        // Transfer with the signed authorization, without an allowance.
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(500),
+                sender: customer.clone(),
+                recipient: "bank".into(),
+                authorization: Some(authorization.clone()),
+                denom: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(1, res.messages.len());
+
+        // Try to replay the authorization.
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("non.admin", &[]),
-            ExecuteMsg::AddKyc {
-                id: Option::None,
-                kyc_attr: "bank2.kyc.pb".into(),
+            env.clone(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(500),
+                sender: customer.clone(),
+                recipient: "bank".into(),
+                authorization: Some(authorization),
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::NotFound { .. }) => {}
+            ContractError::InvalidAuthorization { reason } => {
+                assert_eq!(reason, "nonce already used")
+            }
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to add a the kyc attr a second time.
+        // Try to authorize a transfer with another holder's key.
+        let other_key = SigningKey::from_bytes(&[9u8; 32]).unwrap();
+        let payload = AuthorizationPayload {
+            nonce: Uint128::new(2),
+            ..payload
+        };
+        let signature: Signature = other_key.sign(&to_vec(&payload).unwrap());
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::AddKyc {
-                id: Some("bank".into()),
-                kyc_attr: "bank.kyc.pb".into(),
+            env.clone(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(500),
+                sender: customer.clone(),
+                recipient: "bank".into(),
+                authorization: Some(TransferAuthorization {
+                    pub_key: Binary::from(other_key.verifying_key().to_bytes().as_slice()),
+                    signature: Binary::from(signature.as_ref()),
+                    nonce: payload.nonce,
+                    expires: payload.expires,
+                }),
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "kyc attribute already exists")
+            ContractError::InvalidAuthorization { reason } => {
+                assert_eq!(reason, "public key does not match sender")
             }
             _ => panic!("unexpected execute error"),
         }
-    }
-
-    #[test]
-    fn remove_kyc_test() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
-
-        // Init
-        instantiate(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
-            },
-        )
-        .unwrap();
-
-        // Create join member
-        execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
-            },
-        )
-        .unwrap();
-
-        let key = "bank".as_bytes();
-        let config_state = members_read(&deps.storage).load(key).unwrap();
-        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb"]);
 
-        // Remove kyc attribute
-        execute(
+        // Try to transfer with an expired authorization.
+        let mut expired_env = mock_env();
+        expired_env.block.height += 11;
+        let signature: Signature = signing_key.sign(&to_vec(&payload).unwrap());
+        let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("bank", &[]),
-            ExecuteMsg::RemoveKyc {
-                id: None,
-                kyc_attr: "bank.kyc.pb".into(),
+            expired_env,
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(500),
+                sender: customer,
+                recipient: "bank".into(),
+                authorization: Some(TransferAuthorization {
+                    pub_key,
+                    signature: Binary::from(signature.as_ref()),
+                    nonce: payload.nonce,
+                    expires: payload.expires,
+                }),
+                denom: None,
             },
         )
-        .unwrap();
+        .unwrap_err();
 
-        // Ensure we now have the updated kyc attributes.
-        let config_state = members_read(&deps.storage).load(key).unwrap();
-        assert!(config_state.kyc_attrs.is_empty());
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::InvalidAuthorization { reason } => {
+                assert_eq!(reason, "authorization expired")
+            }
+            _ => panic!("unexpected execute error"),
+        }
     }
 
     #[test]
-    fn remove_kyc_test_admin() {
+    fn executor_config_limits() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -2637,191 +9483,244 @@ mod tests {
         )
         .unwrap();
 
-        let key = "bank".as_bytes();
-        let config_state = members_read(&deps.storage).load(key).unwrap();
-        assert_eq!(config_state.kyc_attrs, vec!["bank.kyc.pb"]);
-
-        // Remove new kyc attribute
+        // Add executor
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::RemoveKyc {
-                id: Option::Some("bank".into()),
-                kyc_attr: "bank.kyc.pb".into(),
+            ExecuteMsg::AddExecutor {
+                id: "executor".into(),
             },
         )
         .unwrap();
 
-        // Ensure we now have the updated kyc attribute.
-        let config_state = members_read(&deps.storage).load(key).unwrap();
-        assert!(config_state.kyc_attrs.is_empty());
-    }
-
-    #[test]
-    fn remove_kyc_param_errors() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
-
-        // Init
-        instantiate(
+        // Limit the executor to bank customers, 300 per transfer and 500 per 100 blocks.
+        execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+            ExecuteMsg::SetExecutorConfig {
+                id: "executor".into(),
+                sender_scope: SenderScope::Members {
+                    ids: vec![Addr::unchecked("bank")],
+                },
+                max_per_tx: Some(Uint128::new(300)),
+                window_blocks: Uint128::new(100),
+                window_cap: Some(Uint128::new(500)),
             },
         )
         .unwrap();
 
-        // Create join member
+        // Assume the customer has a balance of tokens + the required attribute.
+        let dcc = coin(2000, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![dcc]);
+
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Allow the executor to transfer from the customer.
         execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            mock_info("customer", &[]),
+            ExecuteMsg::IncreaseAllowance {
+                executor: "executor".into(),
+                amount: Uint128::new(2000),
+                denom: None,
             },
         )
         .unwrap();
 
-        // Try to send funds w/ the kyc message.
-        let funds = coin(1000, "nhash");
+        // Try to transfer more than the max per transfer.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[funds]),
-            ExecuteMsg::RemoveKyc {
-                id: Option::None,
-                kyc_attr: "bank.kyc.pb".into(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(400),
+                sender: "customer".into(),
+                recipient: "bank".into(),
+                authorization: None,
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during kyc remove")
+            ContractError::ExecutorMaxPerTxExceeded { max, amount } => {
+                assert_eq!(max, Uint128::new(300));
+                assert_eq!(amount, Uint128::new(400));
             }
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to remove an empty name.
+        // Transfer within the window cap.
+        for _ in 0..2 {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("executor", &[]),
+                ExecuteMsg::ExecutorTransfer {
+                    amount: Uint128::new(250),
+                    sender: "customer".into(),
+                    recipient: "bank".into(),
+                    authorization: None,
+                    denom: None,
+                },
+            )
+            .unwrap();
+        }
+
+        // Try to transfer past the window cap.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::RemoveKyc {
-                id: Option::None,
-                kyc_attr: "".into(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(1),
+                sender: "customer".into(),
+                recipient: "bank".into(),
+                authorization: None,
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "kyc attribute name is empty")
-            }
+            ContractError::ExecutorWindowCapExceeded { .. } => {}
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to remove a kyc attribute as a non-admin
-        let err = execute(
+        // Transfer again once the first spends leave the window.
+        let executor_transfer = |amount: u128| ExecuteMsg::ExecutorTransfer {
+            amount: Uint128::new(amount),
+            sender: "customer".into(),
+            recipient: "bank".into(),
+            authorization: None,
+            denom: None,
+        };
+        let mut env = mock_env();
+        env.block.height += 100;
+        execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("non.admin", &[]),
-            ExecuteMsg::RemoveKyc {
-                id: Option::None,
-                kyc_attr: "bank.kyc.pb".into(),
-            },
+            env.clone(),
+            mock_info("executor", &[]),
+            executor_transfer(250),
         )
-        .unwrap_err();
+        .unwrap();
 
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::NotFound { .. }) => {}
-            _ => panic!("unexpected execute error"),
-        }
+        // Ensure the spends that left the window were pruned.
+        assert!(
+            executor_spends_read(&deps.storage, &Addr::unchecked("executor"))
+                .may_load(&12345u128.to_be_bytes())
+                .unwrap()
+                .is_none()
+        );
 
-        // Try to remove a kyc attr that does not exist.
+        // Spend the rest of the cap in the last block of the window.
+        env.block.height += 99;
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("executor", &[]),
+            executor_transfer(250),
+        )
+        .unwrap();
+
+        // Ensure spends carry across the window boundary.
+        env.block.height += 1;
         let err = execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::RemoveKyc {
-                id: Option::Some("bank".into()),
-                kyc_attr: "bank2.kyc.pb".into(),
-            },
+            env.clone(),
+            mock_info("executor", &[]),
+            executor_transfer(300),
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "kyc attribute does not exist")
-            }
+            ContractError::ExecutorWindowCapExceeded { .. } => {}
             _ => panic!("unexpected execute error"),
         }
-    }
-
-    #[test]
-    fn set_admin() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
-
-        // Init
-        instantiate(
+        execute(
             deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
-            },
+            env,
+            mock_info("executor", &[]),
+            executor_transfer(250),
         )
         .unwrap();
 
+        // Ensure a window longer than the chain counts every recorded spend.
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::SetAdmin {
-                id: "newadmin".into(),
+            ExecuteMsg::SetExecutorConfig {
+                id: "executor".into(),
+                sender_scope: SenderScope::Any,
+                max_per_tx: None,
+                window_blocks: Uint128::MAX,
+                window_cap: Some(Uint128::new(550)),
             },
         )
         .unwrap();
+        let mut env = mock_env();
+        env.block.height += 300;
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("executor", &[]),
+            executor_transfer(100),
+        )
+        .unwrap_err();
+        match err {
+            ContractError::ExecutorWindowCapExceeded { .. } => {}
+            _ => panic!("unexpected execute error"),
+        }
 
-        // Ensure admin is changed.
-        let state = config_read(&deps.storage).load().unwrap();
-        assert_eq!(state.admin, "newadmin")
-    }
-
-    #[test]
-    fn set_admin_errors() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
-
-        // Init
-        instantiate(
+        // Limit the executor to another member's customers.
+        execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
+            ExecuteMsg::SetExecutorConfig {
+                id: "executor".into(),
+                sender_scope: SenderScope::Members {
+                    ids: vec![Addr::unchecked("bank2")],
+                },
+                max_per_tx: None,
+                window_blocks: Uint128::zero(),
+                window_cap: None,
             },
         )
         .unwrap();
 
-        // Try to send funds w/ the kyc message.
-        let funds = coin(1000, "nhash");
+        // Try to transfer from a sender outside the executor scope.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[funds]),
-            ExecuteMsg::SetAdmin {
-                id: "newadmin".into(),
+            mock_info("executor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(100),
+                sender: "customer".into(),
+                recipient: "bank".into(),
+                authorization: None,
+                denom: None,
             },
         )
         .unwrap_err();
@@ -2829,18 +9728,22 @@ mod tests {
         // Ensure the expected error was returned.
         match err {
             ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during set admin")
+                assert_eq!(msg, "sender is outside executor scope")
             }
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to set name by user not admin
+        // Ensure only the admin can set executor limits.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("notadmin", &[]),
-            ExecuteMsg::SetAdmin {
-                id: "newadmin".into(),
+            mock_info("bank", &[]),
+            ExecuteMsg::SetExecutorConfig {
+                id: "executor".into(),
+                sender_scope: SenderScope::Any,
+                max_per_tx: None,
+                window_blocks: Uint128::zero(),
+                window_cap: None,
             },
         )
         .unwrap_err();
@@ -2850,58 +9753,10 @@ mod tests {
             ContractError::Unauthorized {} => {}
             _ => panic!("unexpected execute error"),
         }
-
-        // Try to set name to existing admin.
-        let err = execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::SetAdmin { id: "admin".into() },
-        )
-        .unwrap_err();
-
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "admin address is unchanged")
-            }
-            _ => panic!("unexpected execute error"),
-        }
-    }
-
-    #[test]
-    fn add_executor() {
-        // Create mock deps.
-        let mut deps = mock_provenance_dependencies();
-
-        // Init
-        instantiate(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            InitMsg {
-                denom: "dcc.coin".into(),
-            },
-        )
-        .unwrap();
-
-        execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor".into(),
-            },
-        )
-        .unwrap();
-
-        // Ensure admin is changed.
-        let state = config_read(&deps.storage).load().unwrap();
-        assert_eq!(state.executors, vec!["executor"]);
     }
 
     #[test]
-    fn add_executor_errors() {
+    fn executor_transfer_param_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -2916,76 +9771,74 @@ mod tests {
         )
         .unwrap();
 
-        // Add single executor
+        // Create join member
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor".into(),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
             },
         )
         .unwrap();
 
-        // Try to send funds w/ the kyc message.
-        let funds = coin(1000, "nhash");
-        let err = execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[funds]),
-            ExecuteMsg::AddExecutor {
-                id: "executor2".into(),
-            },
-        )
-        .unwrap_err();
-
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during add executor")
-            }
-            _ => panic!("unexpected execute error"),
-        }
-
-        // Try to add executor by user not admin
-        let err = execute(
+        // Add executor
+        execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("notadmin", &[]),
+            mock_info("admin", &[]),
             ExecuteMsg::AddExecutor {
-                id: "executor2".into(),
+                id: "executor".into(),
             },
         )
-        .unwrap_err();
+        .unwrap();
 
-        // Ensure the expected error was returned.
-        match err {
-            ContractError::Unauthorized {} => {}
-            _ => panic!("unexpected execute error"),
-        }
+        // Assume the customer has a balance of tokens + the required attribute.
+        let dcc = coin(1000, "dcc.coin");
+        deps.querier
+            .mock_querier
+            .update_balance("customer", vec![dcc]);
 
-        // Try to add executor already exists.
+        QueryAttributesRequest::mock_response(
+            &mut deps.querier,
+            QueryAttributesResponse {
+                account: "customer".to_string(),
+                attributes: vec![Attribute {
+                    name: "bank.kyc.pb".to_string(),
+                    value: "ok".as_bytes().to_vec(),
+                    attribute_type: AttributeType::String.into(),
+                    address: "".to_string(),
+                }],
+                pagination: None,
+            },
+        );
+
+        // Try to execute transfer without permission.
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor".into(),
+            mock_info("notexecutor", &[]),
+            ExecuteMsg::ExecutorTransfer {
+                amount: Uint128::new(500),
+                sender: "customer".into(),
+                recipient: "bank".into(),
+                authorization: None,
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "executor already exists")
-            }
+            ContractError::Unauthorized {} => {}
             _ => panic!("unexpected execute error"),
         }
     }
 
     #[test]
-    fn remove_executor() {
+    fn set_member_limit() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -3000,13 +9853,15 @@ mod tests {
         )
         .unwrap();
 
-        // Add executors
+        // Create join member
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor1".into(),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
             },
         )
         .unwrap();
@@ -3015,29 +9870,37 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor2".into(),
+            ExecuteMsg::SetMemberLimit {
+                id: "bank".into(),
+                max_supply: Some(Uint128::new(1000)),
+                denom: None,
             },
         )
         .unwrap();
 
+        // Ensure max supply is set.
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.max_supply, Some(Uint128::new(1000)));
+
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::RemoveExecutor {
-                id: "executor1".into(),
+            ExecuteMsg::SetMemberLimit {
+                id: "bank".into(),
+                max_supply: None,
+                denom: None,
             },
         )
         .unwrap();
 
-        // Ensure admin is changed.
-        let state = config_read(&deps.storage).load().unwrap();
-        assert_eq!(state.executors, vec!["executor2"]);
+        // Ensure max supply is cleared.
+        let member = members_read(&deps.storage).load(b"bank").unwrap();
+        assert_eq!(member.max_supply, None);
     }
 
     #[test]
-    fn remove_executor_errors() {
+    fn set_member_limit_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -3052,54 +9915,48 @@ mod tests {
         )
         .unwrap();
 
-        // Add executors
-        execute(
-            deps.as_mut(),
-            mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor1".into(),
-            },
-        )
-        .unwrap();
-
+        // Create join member
         execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor2".into(),
+            ExecuteMsg::Join {
+                id: "bank".into(),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into()],
             },
         )
         .unwrap();
 
-        // Try to send funds w/ the kyc message.
+        // Try to send funds w/ the limit message.
         let funds = coin(1000, "nhash");
         let err = execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[funds]),
-            ExecuteMsg::RemoveExecutor {
-                id: "executor2".into(),
+            ExecuteMsg::SetMemberLimit {
+                id: "bank".into(),
+                max_supply: Some(Uint128::new(1000)),
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "no funds should be sent during remove executor")
-            }
+            ContractError::FundsNotAllowed {} => {}
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to remove executor by user not admin
+        // Try to set limit by user not admin
         let err = execute(
             deps.as_mut(),
             mock_env(),
-            mock_info("notadmin", &[]),
-            ExecuteMsg::RemoveExecutor {
-                id: "executor2".into(),
+            mock_info("bank", &[]),
+            ExecuteMsg::SetMemberLimit {
+                id: "bank".into(),
+                max_supply: Some(Uint128::new(1000)),
+                denom: None,
             },
         )
         .unwrap_err();
@@ -3110,36 +9967,35 @@ mod tests {
             _ => panic!("unexpected execute error"),
         }
 
-        // Try to remove executor does not exist.
+        // Try to set limit for a member that doesn't exist.
         let err = execute(
             deps.as_mut(),
             mock_env(),
             mock_info("admin", &[]),
-            ExecuteMsg::RemoveExecutor {
-                id: "executor".into(),
+            ExecuteMsg::SetMemberLimit {
+                id: "bank1".into(),
+                max_supply: Some(Uint128::new(1000)),
+                denom: None,
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Std(StdError::GenericErr { msg, .. }) => {
-                assert_eq!(msg, "executor does not exist")
-            }
+            ContractError::MemberNotFound { id } => assert_eq!(id, "bank1"),
             _ => panic!("unexpected execute error"),
         }
     }
 
     #[test]
-    fn executor_transfer_test() {
+    fn set_removal_delay_errors() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
-        let env = mock_env();
 
         // Init
         instantiate(
             deps.as_mut(),
-            env.clone(),
+            mock_env(),
             mock_info("admin", &[]),
             InitMsg {
                 denom: "dcc.coin".into(),
@@ -3147,88 +10003,120 @@ mod tests {
         )
         .unwrap();
 
-        // Create join member
-        execute(
+        // Try to send funds w/ the removal delay message.
+        let funds = coin(1000, "nhash");
+        let err = execute(
             deps.as_mut(),
-            env.clone(),
-            mock_info("admin", &[]),
-            ExecuteMsg::Join {
-                id: "bank".into(),
-                name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+            mock_env(),
+            mock_info("admin", &[funds]),
+            ExecuteMsg::SetRemovalDelay {
+                blocks: Uint128::new(100),
             },
         )
-        .unwrap();
+        .unwrap_err();
 
-        // Add executor
-        execute(
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::FundsNotAllowed {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+
+        // Try to set the removal delay by user not admin
+        let err = execute(
             deps.as_mut(),
-            env.clone(),
-            mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor".into(),
+            mock_env(),
+            mock_info("notadmin", &[]),
+            ExecuteMsg::SetRemovalDelay {
+                blocks: Uint128::new(100),
             },
         )
-        .unwrap();
+        .unwrap_err();
 
-        // Assume the customer has a balance of tokens + the required attribute.
-        let dcc = coin(1000, "dcc.coin");
-        deps.querier
-            .mock_querier
-            .update_balance("customer", vec![dcc]);
+        // Ensure the expected error was returned.
+        match err {
+            ContractError::Unauthorized {} => {}
+            _ => panic!("unexpected execute error"),
+        }
+    }
 
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: "customer".to_string(),
-                attributes: vec![Attribute {
-                    name: "bank.kyc.pb".to_string(),
-                    value: "ok".as_bytes().to_vec(),
-                    attribute_type: AttributeType::String.into(),
-                    address: "".to_string(),
-                }],
-                pagination: None,
-            },
-        );
+    #[test]
+    fn get_members_paginated() {
+        // Create mock deps.
+        let mut deps = mock_provenance_dependencies();
 
-        // Transfer dcc from the customer to a member bank.
-        let res = execute(
+        // Init
+        instantiate(
             deps.as_mut(),
-            env.clone(),
-            mock_info("executor", &[]),
-            ExecuteMsg::ExecutorTransfer {
-                amount: Uint128::new(500),
-                sender: "customer".into(),
-                recipient: "bank".into(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InitMsg {
+                denom: "dcc.coin".into(),
             },
         )
         .unwrap();
 
-        // Ensure message was created.
-        assert_eq!(1, res.messages.len());
-        match &res.messages[0].msg {
-            CosmosMsg::Stargate { type_url, value } => {
-                let expected: Binary = MsgTransferRequest {
-                    amount: Some(Coin {
-                        denom: "dcc.coin".to_string(),
-                        amount: "500".to_string(),
-                    }),
-                    administrator: env.contract.address.to_string(),
-                    from_address: "customer".to_string(),
-                    to_address: "bank".to_string(),
-                }
-                .try_into()
-                .unwrap();
-
-                assert_eq!(type_url, "/provenance.marker.v1.MsgTransferRequest");
-                assert_eq!(value, &expected)
-            }
-            _ => panic!("unexpected cosmos message"),
+        // Create join members
+        for id in ["bank1", "bank2", "bank3"] {
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("admin", &[]),
+                ExecuteMsg::Join {
+                    id: id.into(),
+                    name: id.into(),
+                    kyc_attrs: vec![format!("{}.kyc.pb", id)],
+                },
+            )
+            .unwrap();
         }
+
+        // Query the first page.
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetMembers {
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        let page: Members = from_binary(&bin).unwrap();
+        let ids: Vec<&str> = page.members.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["bank1", "bank2"]);
+        assert_eq!(page.next_key, Some("bank2".to_string()));
+
+        // Query the next page.
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetMembers {
+                start_after: page.next_key,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        let page: Members = from_binary(&bin).unwrap();
+        let ids: Vec<&str> = page.members.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["bank3"]);
+        assert_eq!(page.next_key, None);
+
+        // Query with the default limit.
+        let bin = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetMembers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        let page: Members = from_binary(&bin).unwrap();
+        assert_eq!(page.members.len(), 3);
+        assert_eq!(page.next_key, None);
     }
 
     #[test]
-    fn executor_transfer_param_errors() {
+    fn get_member_by_kyc_attr() {
         // Create mock deps.
         let mut deps = mock_provenance_dependencies();
 
@@ -3251,59 +10139,37 @@ mod tests {
             ExecuteMsg::Join {
                 id: "bank".into(),
                 name: "bank".into(),
-                kyc_attrs: vec!["bank.kyc.pb".into()],
+                kyc_attrs: vec!["bank.kyc.pb".into(), "bank.omni.kyc.pb".into()],
             },
         )
         .unwrap();
 
-        // Add executor
-        execute(
-            deps.as_mut(),
+        // Query the member by one of its kyc attributes.
+        let bin = query(
+            deps.as_ref(),
             mock_env(),
-            mock_info("admin", &[]),
-            ExecuteMsg::AddExecutor {
-                id: "executor".into(),
+            QueryMsg::GetMemberByKycAttr {
+                kyc_attr: "bank.omni.kyc.pb".into(),
             },
         )
         .unwrap();
+        let member: MemberV2 = from_binary(&bin).unwrap();
+        assert_eq!(member.id, "bank");
 
-        // Assume the customer has a balance of tokens + the required attribute.
-        let dcc = coin(1000, "dcc.coin");
-        deps.querier
-            .mock_querier
-            .update_balance("customer", vec![dcc]);
-
-        QueryAttributeRequest::mock_response(
-            &mut deps.querier,
-            QueryAttributeResponse {
-                account: "customer".to_string(),
-                attributes: vec![Attribute {
-                    name: "bank.kyc.pb".to_string(),
-                    value: "ok".as_bytes().to_vec(),
-                    attribute_type: AttributeType::String.into(),
-                    address: "".to_string(),
-                }],
-                pagination: None,
-            },
-        );
-
-        // Try to execute transfer without permission.
-        let err = execute(
-            deps.as_mut(),
+        // Try to query by an unknown kyc attribute.
+        let err = query(
+            deps.as_ref(),
             mock_env(),
-            mock_info("notexecutor", &[]),
-            ExecuteMsg::ExecutorTransfer {
-                amount: Uint128::new(500),
-                sender: "customer".into(),
-                recipient: "bank".into(),
+            QueryMsg::GetMemberByKycAttr {
+                kyc_attr: "other.kyc.pb".into(),
             },
         )
         .unwrap_err();
 
         // Ensure the expected error was returned.
         match err {
-            ContractError::Unauthorized {} => {}
-            _ => panic!("unexpected execute error"),
+            ContractError::Std(StdError::NotFound { .. }) => {}
+            _ => panic!("unexpected query error"),
         }
     }
 
@@ -3392,8 +10258,10 @@ mod tests {
         assert_eq!(state.admin, Addr::unchecked("id"));
 
         // Validate members migrated
-        let members = get_members(deps.as_ref()).unwrap();
-        assert_eq!(members.len(), 1);
+        let members = members_read(&deps.storage)
+            .range(None, None, Order::Ascending)
+            .count();
+        assert_eq!(members, 1);
 
         // Validate updated version set
         assert!(get_contract_version(&deps.storage).is_ok());
@@ -3409,6 +10277,11 @@ mod tests {
                 admin: Addr::unchecked("id"),
                 denom: "dcc.coin".to_string(),
                 executors: vec![],
+                removal_delay: Uint128::zero(),
+                pending_admin: None,
+                quorum_pct: Decimal::percent(67),
+                vote_duration: Uint128::new(5000),
+                paused: vec![],
             })
             .unwrap();
 
@@ -0,0 +1,128 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::msg::MigrateMsg;
+use crate::state::{config_read, PausedScope};
+use cosmwasm_std::{DepsMut, Storage, Uint128};
+use cosmwasm_storage::{bucket, bucket_read, Bucket, ReadonlyBucket};
+use semver::{Version, VersionReq};
+
+pub static DENOM_KEY: &[u8] = b"denom";
+pub static DENOM_SUPPLY_KEY: &[u8] = b"denomsupply";
+pub static DENOM_MAX_SUPPLY_KEY: &[u8] = b"denommaxsupply";
+
+/// A token denomination managed by the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Denom {
+    // The marker denom.
+    pub denom: String,
+    // Whether the denom can be minted, burned and transferred.
+    pub enabled: bool,
+    // The operation classes paused for this denom only.
+    pub paused: Vec<PausedScope>,
+}
+
+impl Denom {
+    /// A newly registered denom, enabled and not paused.
+    pub fn enabled(denom: String) -> Self {
+        Denom {
+            denom,
+            enabled: true,
+            paused: vec![],
+        }
+    }
+}
+
+pub fn migrate_denoms(
+    deps: DepsMut,
+    current_version: Version,
+    _msg: &MigrateMsg,
+) -> Result<(), ContractError> {
+    let store = deps.storage;
+    // denom registry added in 0.9.0, register the single denom of existing state
+    let upgrade_req = VersionReq::parse("<0.9.0")?;
+
+    if upgrade_req.matches(&current_version) {
+        let state = config_read(store).load()?;
+        if denoms_read(store)
+            .may_load(state.denom.as_bytes())?
+            .is_none()
+        {
+            denoms(store).save(state.denom.as_bytes(), &Denom::enabled(state.denom.clone()))?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn denoms(storage: &mut dyn Storage) -> Bucket<Denom> {
+    bucket(storage, DENOM_KEY)
+}
+
+pub fn denoms_read(storage: &dyn Storage) -> ReadonlyBucket<Denom> {
+    bucket_read(storage, DENOM_KEY)
+}
+
+// The outstanding supply of a denom minted by each member, keyed by member address. The supply
+// of the contract denom predates the registry and is kept on the member.
+pub fn denom_supplies<'a>(storage: &'a mut dyn Storage, denom: &str) -> Bucket<'a, Uint128> {
+    Bucket::multilevel(storage, &[DENOM_SUPPLY_KEY, denom.as_bytes()])
+}
+
+pub fn denom_supplies_read<'a>(
+    storage: &'a dyn Storage,
+    denom: &str,
+) -> ReadonlyBucket<'a, Uint128> {
+    ReadonlyBucket::multilevel(storage, &[DENOM_SUPPLY_KEY, denom.as_bytes()])
+}
+
+// The max outstanding supply of a denom each member can mint, keyed by member address. The max
+// supply of the contract denom predates the registry and is kept on the member.
+pub fn denom_max_supplies<'a>(storage: &'a mut dyn Storage, denom: &str) -> Bucket<'a, Uint128> {
+    Bucket::multilevel(storage, &[DENOM_MAX_SUPPLY_KEY, denom.as_bytes()])
+}
+
+pub fn denom_max_supplies_read<'a>(
+    storage: &'a dyn Storage,
+    denom: &str,
+) -> ReadonlyBucket<'a, Uint128> {
+    ReadonlyBucket::multilevel(storage, &[DENOM_MAX_SUPPLY_KEY, denom.as_bytes()])
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Addr, Decimal, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use semver::Version;
+
+    use crate::denom::{denoms_read, migrate_denoms, Denom};
+    use crate::error::ContractError;
+    use crate::msg::MigrateMsg;
+    use crate::state::{config, StateV2};
+
+    #[test]
+    pub fn migrate_single_denom_to_registry() -> Result<(), ContractError> {
+        let mut deps = mock_provenance_dependencies();
+
+        config(&mut deps.storage).save(&StateV2 {
+            admin: Addr::unchecked("id"),
+            denom: "test.dcc".to_string(),
+            executors: vec![],
+            removal_delay: Uint128::zero(),
+            pending_admin: None,
+            quorum_pct: Decimal::percent(67),
+            vote_duration: Uint128::new(5000),
+            paused: vec![],
+        })?;
+
+        let current_version = Version::parse("0.8.0")?;
+        migrate_denoms(deps.as_mut(), current_version, &MigrateMsg {})?;
+
+        let migrated_denom = denoms_read(&deps.storage).load(b"test.dcc")?;
+        assert_eq!(migrated_denom, Denom::enabled("test.dcc".to_string()));
+
+        Ok(())
+    }
+}
@@ -1,15 +1,147 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use semver::Error as SemverError;
 use thiserror::Error;
 
+use crate::state::PauseScope;
+
+/// Contract errors. Typed variants carry a stable error code in their message so clients can
+/// react to failures without matching on message text. The message prefix is formatted from
+/// `code()`, so the two cannot drift apart.
 #[derive(Error, Debug)]
 pub enum ContractError {
-    #[error("{0}")]
+    #[error("[E{code:03}] {0}", code = self.code())]
     Std(#[from] StdError),
 
-    #[error("Unauthorized")]
+    #[error("[E{code:03}] Unauthorized", code = self.code())]
     Unauthorized {},
 
-    #[error("{0}")]
+    #[error("[E{code:03}] {0}", code = self.code())]
     SemverError(#[from] SemverError),
+
+    #[error("[E{code:03}] Paused: {scope:?}", code = self.code())]
+    Paused { scope: PauseScope },
+
+    #[error("[E{code:03}] Insufficient balance: needed {needed}, available {available}", code = self.code())]
+    InsufficientBalance { needed: Uint128, available: Uint128 },
+
+    #[error("[E{code:03}] Member not found: {id}", code = self.code())]
+    MemberNotFound { id: String },
+
+    #[error("[E{code:03}] Duplicate kyc attribute: {attr}", code = self.code())]
+    DuplicateKycAttr { attr: String },
+
+    #[error("[E{code:03}] Funds are not allowed", code = self.code())]
+    FundsNotAllowed {},
+
+    #[error("[E{code:03}] Missing kyc attribute: {addr}", code = self.code())]
+    MissingKycAttr { addr: String },
+
+    #[error("[E{code:03}] Insufficient allowance: needed {needed}, available {available}", code = self.code())]
+    InsufficientAllowance { needed: Uint128, available: Uint128 },
+
+    #[error("[E{code:03}] Invalid authorization: {reason}", code = self.code())]
+    InvalidAuthorization { reason: String },
+
+    #[error("[E{code:03}] Denom not found: {denom}", code = self.code())]
+    DenomNotFound { denom: String },
+
+    #[error("[E{code:03}] Denom disabled: {denom}", code = self.code())]
+    DenomDisabled { denom: String },
+
+    #[error("[E{code:03}] Duplicate mint reference: {reference}", code = self.code())]
+    DuplicateMintReference { reference: String },
+
+    #[error("[E{code:03}] Address frozen: {addr}", code = self.code())]
+    AddressFrozen { addr: String },
+
+    #[error("[E{code:03}] Member is being removed: {id}", code = self.code())]
+    MemberRemoving { id: String },
+
+    #[error("[E{code:03}] Pending mint not found: {id}", code = self.code())]
+    PendingMintNotFound { id: Uint128 },
+
+    #[error("[E{code:03}] Pending mint expired: {id}", code = self.code())]
+    PendingMintExpired { id: Uint128 },
+
+    #[error("[E{code:03}] Redemption not found: {reference}", code = self.code())]
+    RedemptionNotFound { reference: String },
+
+    #[error(
+        "[E{code:03}] Executor per transaction max exceeded: max {max}, amount {amount}",
+        code = self.code()
+    )]
+    ExecutorMaxPerTxExceeded { max: Uint128, amount: Uint128 },
+
+    #[error(
+        "[E{code:03}] Executor window cap exceeded: cap {cap}, spent {spent}",
+        code = self.code()
+    )]
+    ExecutorWindowCapExceeded { cap: Uint128, spent: Uint128 },
+}
+
+impl ContractError {
+    /// The stable error code for the error.
+    pub fn code(&self) -> u32 {
+        match self {
+            ContractError::Std(_) => 1,
+            ContractError::Unauthorized {} => 2,
+            ContractError::SemverError(_) => 3,
+            ContractError::Paused { .. } => 4,
+            ContractError::InsufficientBalance { .. } => 5,
+            ContractError::MemberNotFound { .. } => 6,
+            ContractError::DuplicateKycAttr { .. } => 7,
+            ContractError::FundsNotAllowed {} => 8,
+            ContractError::MissingKycAttr { .. } => 9,
+            ContractError::InsufficientAllowance { .. } => 10,
+            ContractError::InvalidAuthorization { .. } => 11,
+            ContractError::DenomNotFound { .. } => 12,
+            ContractError::DenomDisabled { .. } => 13,
+            ContractError::DuplicateMintReference { .. } => 14,
+            ContractError::AddressFrozen { .. } => 15,
+            ContractError::MemberRemoving { .. } => 16,
+            ContractError::PendingMintNotFound { .. } => 17,
+            ContractError::PendingMintExpired { .. } => 18,
+            ContractError::RedemptionNotFound { .. } => 19,
+            ContractError::ExecutorMaxPerTxExceeded { .. } => 20,
+            ContractError::ExecutorWindowCapExceeded { .. } => 21,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{StdError, Uint128};
+
+    use crate::error::ContractError;
+
+    #[test]
+    fn error_codes_in_messages() {
+        let err = ContractError::InsufficientBalance {
+            needed: Uint128::new(500),
+            available: Uint128::new(100),
+        };
+        assert_eq!(err.code(), 5);
+        assert_eq!(
+            err.to_string(),
+            "[E005] Insufficient balance: needed 500, available 100"
+        );
+
+        let err = ContractError::Std(StdError::generic_err("invalid mint amount"));
+        assert_eq!(err.code(), 1);
+        assert_eq!(err.to_string(), "[E001] Generic error: invalid mint amount");
+
+        let err = ContractError::FundsNotAllowed {};
+        assert_eq!(err.code(), 8);
+        assert_eq!(err.to_string(), "[E008] Funds are not allowed");
+
+        let err = ContractError::ExecutorWindowCapExceeded {
+            cap: Uint128::new(500),
+            spent: Uint128::new(550),
+        };
+        assert_eq!(err.code(), 21);
+        assert_eq!(
+            err.to_string(),
+            "[E021] Executor window cap exceeded: cap 500, spent 550"
+        );
+    }
 }
@@ -0,0 +1,192 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+
+use cosmwasm_std::{Addr, Event, StdError, StdResult, Timestamp, Uint128};
+
+// The wasm module prefixes the event type, so it's emitted as `wasm-coin_movement`.
+pub static COIN_MOVEMENT_EVENT: &str = "coin_movement";
+
+/// The kind of change a coin movement makes to token holdings.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoinMovementType {
+    Mint,
+    Burn,
+    Transfer,
+}
+
+impl fmt::Display for CoinMovementType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CoinMovementType::Mint => write!(f, "MINT"),
+            CoinMovementType::Burn => write!(f, "BURN"),
+            CoinMovementType::Transfer => write!(f, "TRANSFER"),
+        }
+    }
+}
+
+/// A movement of token, emitted as a `wasm-coin_movement` event by every path that mints, burns
+/// or transfers token. The attributes map onto the records of `coin_movement_schema.json`, except
+/// the txid, which is the hash of the transaction the event is emitted in.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CoinMovement {
+    // The contract address for mints and burns.
+    pub from_address: Addr,
+    // The ID of the member the sending account belongs to, if any.
+    pub from_address_bank_uuid: Option<String>,
+    // The account token is burned from for burns.
+    pub to_address: Addr,
+    // The ID of the member the receiving account belongs to, if any.
+    pub to_address_bank_uuid: Option<String>,
+    pub block_height: Uint128,
+    // The ISO 8601 block time.
+    pub timestamp: String,
+    pub amount: Uint128,
+    pub denom: String,
+    #[serde(rename = "type")]
+    pub movement_type: CoinMovementType,
+}
+
+impl From<CoinMovement> for Event {
+    fn from(movement: CoinMovement) -> Self {
+        Event::new(COIN_MOVEMENT_EVENT)
+            .add_attribute("from_address", movement.from_address)
+            .add_attribute(
+                "from_address_bank_uuid",
+                movement.from_address_bank_uuid.unwrap_or_default(),
+            )
+            .add_attribute("to_address", movement.to_address)
+            .add_attribute(
+                "to_address_bank_uuid",
+                movement.to_address_bank_uuid.unwrap_or_default(),
+            )
+            .add_attribute("block_height", movement.block_height)
+            .add_attribute("timestamp", movement.timestamp)
+            .add_attribute("amount", movement.amount)
+            .add_attribute("denom", movement.denom)
+            .add_attribute("type", movement.movement_type.to_string())
+    }
+}
+
+impl TryFrom<&Event> for CoinMovement {
+    type Error = StdError;
+
+    // Read a coin movement back from an event, with or without the `wasm-` prefix.
+    fn try_from(event: &Event) -> StdResult<Self> {
+        if event.ty.trim_start_matches("wasm-") != COIN_MOVEMENT_EVENT {
+            return Err(StdError::generic_err("not a coin movement event"));
+        }
+        let attr = |key: &str| -> StdResult<String> {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+                .ok_or_else(|| StdError::generic_err(format!("missing attribute: {}", key)))
+        };
+        let bank_uuid = |key: &str| -> StdResult<Option<String>> {
+            attr(key).map(|v| if v.is_empty() { None } else { Some(v) })
+        };
+        let uint = |key: &str| -> StdResult<Uint128> {
+            attr(key)?
+                .parse::<u128>()
+                .map(Uint128::new)
+                .map_err(|_| StdError::generic_err(format!("invalid attribute: {}", key)))
+        };
+        let movement_type = match attr("type")?.as_str() {
+            "MINT" => CoinMovementType::Mint,
+            "BURN" => CoinMovementType::Burn,
+            "TRANSFER" => CoinMovementType::Transfer,
+            _ => return Err(StdError::generic_err("invalid attribute: type")),
+        };
+        Ok(CoinMovement {
+            from_address: Addr::unchecked(attr("from_address")?),
+            from_address_bank_uuid: bank_uuid("from_address_bank_uuid")?,
+            to_address: Addr::unchecked(attr("to_address")?),
+            to_address_bank_uuid: bank_uuid("to_address_bank_uuid")?,
+            block_height: uint("block_height")?,
+            timestamp: attr("timestamp")?,
+            amount: uint("amount")?,
+            denom: attr("denom")?,
+            movement_type,
+        })
+    }
+}
+
+// Format a block time as an ISO 8601 UTC timestamp with millisecond precision.
+pub fn iso8601(time: Timestamp) -> String {
+    let secs = time.seconds();
+    let millis = time.subsec_nanos() / 1_000_000;
+    let (hour, min, sec) = ((secs / 3600) % 24, (secs / 60) % 60, secs % 60);
+
+    // Convert days since the unix epoch to a civil date.
+    let z = (secs / 86400) as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year, month, day, hour, min, sec, millis
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use cosmwasm_std::{Addr, Event, Timestamp, Uint128};
+
+    use crate::event::{iso8601, CoinMovement, CoinMovementType};
+
+    #[test]
+    fn iso8601_block_time() {
+        assert_eq!(
+            iso8601(Timestamp::from_seconds(0)),
+            "1970-01-01T00:00:00.000Z"
+        );
+        assert_eq!(
+            iso8601(Timestamp::from_nanos(1_629_112_456_511_000_000)),
+            "2021-08-16T11:14:16.511Z"
+        );
+        assert_eq!(
+            iso8601(Timestamp::from_seconds(951_782_400)),
+            "2000-02-29T00:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn coin_movement_event_round_trip() {
+        let movement = CoinMovement {
+            from_address: Addr::unchecked("contract"),
+            from_address_bank_uuid: None,
+            to_address: Addr::unchecked("bank"),
+            to_address_bank_uuid: Some("bank".into()),
+            block_height: Uint128::new(12345),
+            timestamp: "2021-08-16T11:14:16.511Z".into(),
+            amount: Uint128::new(100),
+            denom: "dcc.coin".into(),
+            movement_type: CoinMovementType::Mint,
+        };
+
+        let event = Event::from(movement.clone());
+        assert_eq!(event.ty, "coin_movement");
+        assert_eq!(event.attributes.len(), 9);
+        assert_eq!(event.attributes[1].value, "");
+        assert_eq!(event.attributes[8].value, "MINT");
+
+        let mut emitted = event;
+        emitted.ty = "wasm-coin_movement".into();
+        assert_eq!(CoinMovement::try_from(&emitted).unwrap(), movement);
+
+        assert!(CoinMovement::try_from(&Event::new("transfer")).is_err());
+    }
+}
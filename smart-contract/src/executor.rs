@@ -0,0 +1,158 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::{Addr, Binary, StdError, StdResult, Storage, Uint128};
+use cosmwasm_storage::{bucket, bucket_read, Bucket, ReadonlyBucket};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+pub static EXECUTOR_CONFIG_KEY: &[u8] = b"executor";
+pub static ALLOWANCE_KEY: &[u8] = b"allowance";
+pub static AUTHORIZATION_NONCE_KEY: &[u8] = b"nonce";
+pub static EXECUTOR_SPEND_KEY: &[u8] = b"executorspend";
+
+/// The accounts an executor can transfer token from.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SenderScope {
+    // Any account holding token.
+    Any,
+    // Accounts of, or kyc'd by, the listed members.
+    Members { ids: Vec<Addr> },
+}
+
+/// Executor spending limits.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ExecutorConfig {
+    // The executor ID (also the executor address).
+    pub id: Addr,
+    // The accounts the executor can transfer token from.
+    pub sender_scope: SenderScope,
+    // The max amount of token per transfer (unlimited if not set).
+    pub max_per_tx: Option<Uint128>,
+    // The number of blocks in the rolling spending window.
+    pub window_blocks: Uint128,
+    // The max amount of token transferred within the last window_blocks blocks (unlimited if
+    // not set).
+    pub window_cap: Option<Uint128>,
+}
+
+impl ExecutorConfig {
+    /// The default config for an executor, without spending limits.
+    pub fn unlimited(id: Addr) -> Self {
+        ExecutorConfig {
+            id,
+            sender_scope: SenderScope::Any,
+            max_per_tx: None,
+            window_blocks: Uint128::zero(),
+            window_cap: None,
+        }
+    }
+}
+
+/// The amount of token a holder allows an executor to transfer on its behalf.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Allowance {
+    pub holder: Addr,
+    pub executor: Addr,
+    pub amount: Uint128,
+}
+
+/// A holder's signed authorization of a single executor transfer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TransferAuthorization {
+    // The holder's compressed secp256k1 public key.
+    pub pub_key: Binary,
+    // The holder's signature of the sha256 hash of the authorization payload.
+    pub signature: Binary,
+    // Must be greater than the last nonce the holder authorized a transfer with.
+    pub nonce: Uint128,
+    // The block height the authorization expires after.
+    pub expires: Uint128,
+}
+
+/// The payload a holder signs, serialized as JSON, to authorize an executor transfer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AuthorizationPayload {
+    pub contract: Addr,
+    pub executor: Addr,
+    pub sender: Addr,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub nonce: Uint128,
+    pub expires: Uint128,
+    // Only set for denoms other than the contract denom, so earlier payloads sign the same.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub denom: Option<String>,
+}
+
+// Derive the bech32 account address of a compressed secp256k1 public key.
+pub fn pub_key_address(pub_key: &[u8], hrp: &str) -> StdResult<String> {
+    let hash = Ripemd160::digest(Sha256::digest(pub_key));
+    bech32::encode(hrp, hash.to_base32(), Variant::Bech32)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+pub fn executor_configs(storage: &mut dyn Storage) -> Bucket<ExecutorConfig> {
+    bucket(storage, EXECUTOR_CONFIG_KEY)
+}
+
+pub fn executor_configs_read(storage: &dyn Storage) -> ReadonlyBucket<ExecutorConfig> {
+    bucket_read(storage, EXECUTOR_CONFIG_KEY)
+}
+
+// Allowances granted by a holder, keyed by executor address. Allowances of the contract denom
+// (no denom) predate the denom registry and are kept without a denom namespace.
+// Amounts of token an executor transferred, keyed by block height.
+pub fn executor_spends<'a>(storage: &'a mut dyn Storage, executor: &Addr) -> Bucket<'a, Uint128> {
+    Bucket::multilevel(storage, &[EXECUTOR_SPEND_KEY, executor.as_bytes()])
+}
+
+pub fn executor_spends_read<'a>(
+    storage: &'a dyn Storage,
+    executor: &Addr,
+) -> ReadonlyBucket<'a, Uint128> {
+    ReadonlyBucket::multilevel(storage, &[EXECUTOR_SPEND_KEY, executor.as_bytes()])
+}
+
+pub fn allowances<'a>(
+    storage: &'a mut dyn Storage,
+    holder: &Addr,
+    denom: Option<&str>,
+) -> Bucket<'a, Allowance> {
+    match denom {
+        None => Bucket::multilevel(storage, &[ALLOWANCE_KEY, holder.as_bytes()]),
+        Some(denom) => Bucket::multilevel(
+            storage,
+            &[ALLOWANCE_KEY, holder.as_bytes(), denom.as_bytes()],
+        ),
+    }
+}
+
+pub fn allowances_read<'a>(
+    storage: &'a dyn Storage,
+    holder: &Addr,
+    denom: Option<&str>,
+) -> ReadonlyBucket<'a, Allowance> {
+    match denom {
+        None => ReadonlyBucket::multilevel(storage, &[ALLOWANCE_KEY, holder.as_bytes()]),
+        Some(denom) => ReadonlyBucket::multilevel(
+            storage,
+            &[ALLOWANCE_KEY, holder.as_bytes(), denom.as_bytes()],
+        ),
+    }
+}
+
+// The last authorization nonce used by a holder, keyed by holder address.
+pub fn authorization_nonces(storage: &mut dyn Storage) -> Bucket<Uint128> {
+    bucket(storage, AUTHORIZATION_NONCE_KEY)
+}
+
+pub fn authorization_nonces_read(storage: &dyn Storage) -> ReadonlyBucket<Uint128> {
+    bucket_read(storage, AUTHORIZATION_NONCE_KEY)
+}
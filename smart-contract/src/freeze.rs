@@ -0,0 +1,79 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cosmwasm_storage::{
+    bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket, ReadonlySingleton,
+    Singleton,
+};
+
+pub static FROZEN_ADDRESS_KEY: &[u8] = b"frozen";
+pub static FORCE_TRANSFER_KEY: &[u8] = b"forcetransfer";
+pub static FORCE_TRANSFER_SEQ_KEY: &[u8] = b"forcetransferseq";
+
+/// Compliance hold on an address.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FrozenAddress {
+    // The frozen address.
+    pub address: Addr,
+    // The block height the address was frozen at.
+    pub frozen: Uint128,
+    // The reason the address was frozen.
+    pub reason: String,
+}
+
+/// Token moved out of a frozen address by admin.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ForceTransfer {
+    pub id: Uint128,
+    // The frozen address the token was moved from.
+    pub address: Addr,
+    pub recipient: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    // The reason the token was moved.
+    pub reason: String,
+    // The block height the token was moved at.
+    pub height: Uint128,
+}
+
+// Determine whether the address is frozen.
+pub fn is_frozen(storage: &dyn Storage, address: &Addr) -> StdResult<bool> {
+    Ok(frozen_addresses_read(storage)
+        .may_load(address.as_bytes())?
+        .is_some())
+}
+
+pub fn frozen_addresses(storage: &mut dyn Storage) -> Bucket<FrozenAddress> {
+    bucket(storage, FROZEN_ADDRESS_KEY)
+}
+
+pub fn frozen_addresses_read(storage: &dyn Storage) -> ReadonlyBucket<FrozenAddress> {
+    bucket_read(storage, FROZEN_ADDRESS_KEY)
+}
+
+// Force transfers out of a frozen address, keyed by big endian ID.
+pub fn force_transfers<'a>(
+    storage: &'a mut dyn Storage,
+    address: &Addr,
+) -> Bucket<'a, ForceTransfer> {
+    Bucket::multilevel(storage, &[FORCE_TRANSFER_KEY, address.as_bytes()])
+}
+
+pub fn force_transfers_read<'a>(
+    storage: &'a dyn Storage,
+    address: &Addr,
+) -> ReadonlyBucket<'a, ForceTransfer> {
+    ReadonlyBucket::multilevel(storage, &[FORCE_TRANSFER_KEY, address.as_bytes()])
+}
+
+// The last force transfer ID.
+pub fn force_transfer_seq(storage: &mut dyn Storage) -> Singleton<Uint128> {
+    singleton(storage, FORCE_TRANSFER_SEQ_KEY)
+}
+
+pub fn force_transfer_seq_read(storage: &dyn Storage) -> ReadonlySingleton<Uint128> {
+    singleton_read(storage, FORCE_TRANSFER_SEQ_KEY)
+}
@@ -9,6 +9,7 @@ use cosmwasm_storage::{bucket, bucket_read, Bucket, ReadonlyBucket};
 use semver::{Version, VersionReq};
 
 pub static JOIN_PROPOSAL_KEY: &[u8] = b"proposal";
+pub static JOIN_PROPOSAL_V2_KEY: &[u8] = b"proposalv2";
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -37,6 +38,30 @@ pub struct JoinProposal {
     pub admin_vote: Option<VoteChoice>,
 }
 
+/// Join proposal state.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct JoinProposalV2 {
+    // The proposal ID (also the proposed member address).
+    pub id: Addr,
+    // The name of the proposed member.
+    pub name: String,
+    // KYC attributes required for holding dcc tokens.
+    pub kyc_attrs: Vec<String>,
+    // The member or admin that created the proposal.
+    pub proposer: Addr,
+    // The block height the proposal was created at.
+    pub created: Uint128,
+    // The block height the voting window closes.
+    pub expires: Uint128,
+    // The sum of the weights of members that voted 'yes'.
+    pub yes: Uint128,
+    // The sum of weights of members that voted 'no'.
+    pub no: Uint128,
+    // The addresses of members that have voted.
+    pub voters: Vec<Addr>,
+}
+
 #[allow(deprecated)]
 pub fn migrate_join_proposals(
     deps: DepsMut,
@@ -70,6 +95,14 @@ pub fn get_legacy_proposal_ids(storage: &dyn Storage) -> Vec<Vec<u8>> {
         .collect()
 }
 
+pub fn join_proposals(storage: &mut dyn Storage) -> Bucket<JoinProposalV2> {
+    bucket(storage, JOIN_PROPOSAL_V2_KEY)
+}
+
+pub fn join_proposals_read(storage: &dyn Storage) -> ReadonlyBucket<JoinProposalV2> {
+    bucket_read(storage, JOIN_PROPOSAL_V2_KEY)
+}
+
 #[allow(deprecated)]
 pub fn legacy_join_proposals(storage: &mut dyn Storage) -> Bucket<JoinProposal> {
     bucket(storage, JOIN_PROPOSAL_KEY)
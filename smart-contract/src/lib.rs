@@ -1,7 +1,13 @@
 #![warn(clippy::all)]
 pub mod contract;
+pub mod denom;
 pub mod error;
+pub mod event;
+pub mod executor;
+pub mod freeze;
 pub mod join_proposal;
 pub mod member;
+pub mod mint;
 pub mod msg;
+pub mod redemption;
 pub mod state;
@@ -3,12 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::ContractError;
 use crate::msg::MigrateMsg;
-use cosmwasm_std::{Addr, DepsMut, Order, Storage, Uint128};
+use cosmwasm_std::{Addr, DepsMut, Order, StdResult, Storage, Uint128};
 use cosmwasm_storage::{bucket, bucket_read, Bucket, ReadonlyBucket};
 use semver::{Version, VersionReq};
 
 pub static MEMBER_KEY: &[u8] = b"member";
 pub static MEMBER_V2_KEY: &[u8] = b"memberv2";
+pub static KYC_ATTR_INDEX_KEY: &[u8] = b"kycattr";
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -36,13 +37,28 @@ pub struct Member {
 pub struct MemberV2 {
     // The member ID (also the member address).
     pub id: Addr,
-    // TODO - determine if we still need to keep track of supply or limit max supply
     // The block height membership was accepted.
     pub joined: Uint128,
     // The name of the member (or just the address if not provided in the join proposal).
     pub name: String,
     // KYC attributes required for holding dcc tokens.
     pub kyc_attrs: Vec<String>,
+    // The current outstanding supply of dcc minted by the member.
+    #[serde(default)]
+    pub supply: Uint128,
+    // The max outstanding supply of dcc the member can mint (unlimited if not set).
+    #[serde(default)]
+    pub max_supply: Option<Uint128>,
+    // The block height member removal can be finalized at, if the member is being removed.
+    #[serde(default)]
+    pub removal_height: Option<Uint128>,
+    // The member's voting weight for join proposals.
+    #[serde(default = "default_weight")]
+    pub weight: Uint128,
+}
+
+pub fn default_weight() -> Uint128 {
+    Uint128::one()
 }
 
 #[allow(deprecated)]
@@ -53,6 +69,10 @@ impl From<Member> for MemberV2 {
             joined: member.joined,
             name: member.name,
             kyc_attrs: Vec::new(),
+            supply: member.supply,
+            max_supply: Some(member.max_supply),
+            removal_height: None,
+            weight: member.weight,
         }
     }
 }
@@ -77,6 +97,22 @@ pub fn migrate_members(
         }
     }
 
+    // kyc attribute index added in 0.8.0, build it from existing member kyc attributes
+    let index_req = VersionReq::parse("<0.8.0")?;
+
+    if index_req.matches(&current_version) {
+        let existing_members: Vec<MemberV2> = members_read(store)
+            .range(None, None, Order::Ascending)
+            .map(|item| item.map(|(_, member)| member))
+            .collect::<StdResult<Vec<MemberV2>>>()?;
+
+        for existing_member in existing_members {
+            for kyc_attr in &existing_member.kyc_attrs {
+                kyc_attr_index(store).save(kyc_attr.as_bytes(), &existing_member.id)?;
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -98,6 +134,15 @@ pub fn members_read(storage: &dyn Storage) -> ReadonlyBucket<MemberV2> {
     bucket_read(storage, MEMBER_V2_KEY)
 }
 
+/// Index of kyc attribute name to the ID of the member that owns it.
+pub fn kyc_attr_index(storage: &mut dyn Storage) -> Bucket<Addr> {
+    bucket(storage, KYC_ATTR_INDEX_KEY)
+}
+
+pub fn kyc_attr_index_read(storage: &dyn Storage) -> ReadonlyBucket<Addr> {
+    bucket_read(storage, KYC_ATTR_INDEX_KEY)
+}
+
 #[allow(deprecated)]
 pub fn get_legacy_member_ids(storage: &dyn Storage) -> Vec<Vec<u8>> {
     legacy_members_read(storage)
@@ -118,7 +163,8 @@ mod tests {
     use crate::error::ContractError;
     #[allow(deprecated)]
     use crate::member::{
-        get_legacy_member_ids, legacy_members, members_read, migrate_members, Member, MemberV2,
+        get_legacy_member_ids, kyc_attr_index_read, legacy_members, members, members_read,
+        migrate_members, Member, MemberV2,
     };
     use crate::msg::MigrateMsg;
 
@@ -153,6 +199,10 @@ mod tests {
                 joined: Uint128::new(50100),
                 name: "bank".into(),
                 kyc_attrs: Vec::new(),
+                supply: Uint128::new(100),
+                max_supply: Some(Uint128::new(1000)),
+                removal_height: None,
+                weight: Uint128::new(1000),
             }
         );
 
@@ -161,4 +211,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    pub fn migrate_kyc_attr_index() -> Result<(), ContractError> {
+        let mut deps = mock_provenance_dependencies();
+
+        members(&mut deps.storage).save(
+            b"id",
+            &MemberV2 {
+                id: Addr::unchecked("id"),
+                joined: Uint128::new(50100),
+                name: "bank".into(),
+                kyc_attrs: vec!["bank.kyc.pb".into(), "bank2.kyc.pb".into()],
+                supply: Uint128::zero(),
+                max_supply: None,
+                removal_height: None,
+                weight: Uint128::one(),
+            },
+        )?;
+
+        let current_version = Version::parse("0.7.0")?;
+        migrate_members(deps.as_mut(), current_version, &MigrateMsg {})?;
+
+        let index = kyc_attr_index_read(&deps.storage);
+        assert_eq!(index.load(b"bank.kyc.pb")?, Addr::unchecked("id"));
+        assert_eq!(index.load(b"bank2.kyc.pb")?, Addr::unchecked("id"));
+
+        Ok(())
+    }
 }
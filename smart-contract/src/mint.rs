@@ -0,0 +1,116 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{Addr, Storage, Uint128};
+use cosmwasm_storage::{
+    bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket, ReadonlySingleton,
+    Singleton,
+};
+
+pub static MINT_REFERENCE_KEY: &[u8] = b"mintref";
+pub static MINT_APPROVAL_CONFIG_KEY: &[u8] = b"mintapproval";
+pub static PENDING_MINT_KEY: &[u8] = b"pendingmint";
+pub static PENDING_MINT_SEQ_KEY: &[u8] = b"pendingmintseq";
+pub static PENDING_MINT_REFERENCE_KEY: &[u8] = b"pendingmintref";
+
+/// A mint made against a bank deposit reference.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MintRecord {
+    // The bank's fiat deposit ID.
+    pub reference: String,
+    // The member that minted the token.
+    pub member: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    // The account the minted token was withdrawn to.
+    pub address: Addr,
+    // The block height of the mint.
+    pub height: Uint128,
+}
+
+// Mints made by a member, keyed by deposit reference.
+pub fn mint_references<'a>(storage: &'a mut dyn Storage, member: &Addr) -> Bucket<'a, MintRecord> {
+    Bucket::multilevel(storage, &[MINT_REFERENCE_KEY, member.as_bytes()])
+}
+
+pub fn mint_references_read<'a>(
+    storage: &'a dyn Storage,
+    member: &Addr,
+) -> ReadonlyBucket<'a, MintRecord> {
+    ReadonlyBucket::multilevel(storage, &[MINT_REFERENCE_KEY, member.as_bytes()])
+}
+
+/// Dual authorization of a member's large mints.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MintApprovalConfig {
+    // The member ID (also the member address).
+    pub member: Addr,
+    // Mints above this amount require approval.
+    pub threshold: Uint128,
+    // The address that approves the member's mints, besides the admin.
+    pub approver: Addr,
+    // The number of blocks a pending mint can be approved for.
+    pub window_blocks: Uint128,
+}
+
+/// A mint awaiting approval.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingMint {
+    pub id: Uint128,
+    // The member that requested the mint.
+    pub member: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    // The account to withdraw the minted token to.
+    pub address: Addr,
+    // The bank's fiat deposit ID, if provided.
+    pub reference: Option<String>,
+    // The block height the mint was requested at.
+    pub created: Uint128,
+    // The block height the mint can no longer be approved after.
+    pub expires: Uint128,
+}
+
+pub fn mint_approval_configs(storage: &mut dyn Storage) -> Bucket<MintApprovalConfig> {
+    bucket(storage, MINT_APPROVAL_CONFIG_KEY)
+}
+
+pub fn mint_approval_configs_read(storage: &dyn Storage) -> ReadonlyBucket<MintApprovalConfig> {
+    bucket_read(storage, MINT_APPROVAL_CONFIG_KEY)
+}
+
+// Pending mints, keyed by big endian ID.
+pub fn pending_mints(storage: &mut dyn Storage) -> Bucket<PendingMint> {
+    bucket(storage, PENDING_MINT_KEY)
+}
+
+pub fn pending_mints_read(storage: &dyn Storage) -> ReadonlyBucket<PendingMint> {
+    bucket_read(storage, PENDING_MINT_KEY)
+}
+
+// The last pending mint ID.
+pub fn pending_mint_seq(storage: &mut dyn Storage) -> Singleton<Uint128> {
+    singleton(storage, PENDING_MINT_SEQ_KEY)
+}
+
+pub fn pending_mint_seq_read(storage: &dyn Storage) -> ReadonlySingleton<Uint128> {
+    singleton_read(storage, PENDING_MINT_SEQ_KEY)
+}
+
+// Pending mint IDs of a member, keyed by deposit reference.
+pub fn pending_mint_references<'a>(
+    storage: &'a mut dyn Storage,
+    member: &Addr,
+) -> Bucket<'a, Uint128> {
+    Bucket::multilevel(storage, &[PENDING_MINT_REFERENCE_KEY, member.as_bytes()])
+}
+
+pub fn pending_mint_references_read<'a>(
+    storage: &'a dyn Storage,
+    member: &Addr,
+) -> ReadonlyBucket<'a, Uint128> {
+    ReadonlyBucket::multilevel(storage, &[PENDING_MINT_REFERENCE_KEY, member.as_bytes()])
+}
@@ -1,8 +1,13 @@
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::executor::{SenderScope, TransferAuthorization};
+use crate::freeze::ForceTransfer;
 use crate::member::MemberV2;
+use crate::mint::PendingMint;
+use crate::redemption::Redemption;
+use crate::state::PauseScope;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InitMsg {
@@ -12,7 +17,6 @@ pub struct InitMsg {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-#[deprecated(since = "0.5.0")]
 pub enum VoteChoice {
     Yes,
     No,
@@ -21,28 +25,71 @@ pub enum VoteChoice {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    // Create a proposal to join the consortium.
+    // Add a member as admin, bypassing the member vote. Only for bootstrapping and emergencies.
     Join {
         id: String,
         name: String,
         kyc_attrs: Vec<String>,
     },
+    // Propose a member to join the consortium by member vote.
+    ProposeJoin {
+        id: String,
+        name: String,
+        kyc_attrs: Vec<String>,
+    },
+    // Vote on a join proposal as a member.
+    Vote {
+        id: String,
+        choice: VoteChoice,
+    },
+    // Add the proposed member once a join proposal has reached quorum.
+    ExecuteProposal {
+        id: String,
+    },
+    // Remove a join proposal that did not reach quorum before its voting window closed.
+    ExpireProposal {
+        id: String,
+    },
+    // Start removing a member from the consortium.
     Remove {
         id: String,
     },
+    // Finish removing a member after the removal delay has passed.
+    FinalizeRemoval {
+        id: String,
+    },
     // Transfer dcc.
     Transfer {
         amount: Uint128,
         recipient: String,
+        denom: Option<String>, // If not provided, the contract denom
     },
     // Increase a member's supply of reserve tokens.
     Mint {
         amount: Uint128,
         address: Option<String>, // If provided, withdraw dcc tokens here pending kyc checks
+        denom: Option<String>,   // If not provided, the contract denom
+        reference: Option<String>, // If provided, the bank deposit ID, which can only be minted once
     },
     // Decrease a member's supply of reserve tokens.
     Burn {
         amount: Uint128,
+        denom: Option<String>, // If not provided, the contract denom
+    },
+    // Escrow token to redeem for fiat at a member.
+    Redeem {
+        amount: Uint128,
+        member_id: String,
+        reference: String,
+        denom: Option<String>, // If not provided, the contract denom
+    },
+    // Burn the escrowed token of a redemption as the member.
+    SettleRedemption {
+        reference: String,
+    },
+    // Return the escrowed token of a redemption to the holder as the member.
+    RejectRedemption {
+        reference: String,
     },
     // Add a kyc attribute for member.
     AddKyc {
@@ -54,10 +101,15 @@ pub enum ExecuteMsg {
         id: Option<String>, // If admin, can set the kyc attribute for another member id
         kyc_attr: String,
     },
-    // Set the smart contract admin address.
-    SetAdmin {
+    // Propose a new smart contract admin address.
+    ProposeAdmin {
         id: String,
+        expires_at: Option<Uint128>, // If provided, the block height the proposal expires after
     },
+    // Accept the admin proposal as the proposed admin.
+    AcceptAdmin {},
+    // Cancel a pending admin proposal.
+    CancelAdminProposal {},
     // Add an executor to state.
     AddExecutor {
         id: String,
@@ -71,16 +123,187 @@ pub enum ExecuteMsg {
         amount: Uint128,
         sender: String,
         recipient: String,
+        authorization: Option<TransferAuthorization>, // If not provided, the sender's allowance is used
+        denom: Option<String>,                        // If not provided, the contract denom
+    },
+    // Set the spending limits of an executor.
+    SetExecutorConfig {
+        id: String,
+        sender_scope: SenderScope,
+        max_per_tx: Option<Uint128>, // If not provided, transfers are unlimited
+        window_blocks: Uint128,
+        window_cap: Option<Uint128>, // If not provided, spending windows are unlimited
+    },
+    // Increase the amount an executor can transfer from the sender.
+    IncreaseAllowance {
+        executor: String,
+        amount: Uint128,
+        denom: Option<String>, // If not provided, the contract denom
+    },
+    // Decrease the amount an executor can transfer from the sender.
+    DecreaseAllowance {
+        executor: String,
+        amount: Uint128,
+        denom: Option<String>, // If not provided, the contract denom
+    },
+    // Set the max outstanding supply a member can mint.
+    SetMemberLimit {
+        id: String,
+        max_supply: Option<Uint128>, // If not provided, the member's supply is unlimited
+        denom: Option<String>,       // If not provided, the contract denom
+    },
+    // Set the threshold above which a member's mints require approval.
+    SetMintApproval {
+        id: String,
+        threshold: Option<Uint128>, // If not provided, the member's mints don't require approval
+        approver: Option<String>,   // Required with a threshold, approves besides the admin
+        window_blocks: Uint128,
+    },
+    // Approve a pending mint as the member's approver or admin.
+    ApproveMint {
+        id: Uint128,
+    },
+    // Reject a pending mint as the member's approver or admin.
+    RejectMint {
+        id: Uint128,
+    },
+    // Remove a pending mint whose approval window has passed.
+    ExpirePendingMint {
+        id: Uint128,
+    },
+    // Set the number of blocks between member removal and finalized removal.
+    SetRemovalDelay {
+        blocks: Uint128,
+    },
+    // Set the voting weight of a member.
+    SetMemberWeight {
+        id: String,
+        weight: Uint128,
+    },
+    // Set the join proposal quorum percentage and voting window.
+    SetProposalConfig {
+        quorum_pct: Decimal,
+        vote_duration: Uint128,
+    },
+    // Halt a class of operations.
+    Pause {
+        scope: PauseScope,
+        reason: String,
+        denom: Option<String>, // If provided, pause the denom only
+    },
+    // Resume a paused class of operations.
+    Unpause {
+        scope: PauseScope,
+        denom: Option<String>, // If provided, resume the denom only
+    },
+    // Place a compliance hold on an address.
+    FreezeAddress {
+        id: String,
+        reason: String,
+    },
+    // Remove a compliance hold from an address.
+    UnfreezeAddress {
+        id: String,
+    },
+    // Move dcc out of a frozen address by admin.
+    ForceTransfer {
+        amount: Uint128,
+        sender: String,
+        recipient: String,
+        reason: String,
+        denom: Option<String>, // If not provided, the contract denom
+    },
+    // Register a denom, creating its marker if it doesn't exist.
+    AddDenom {
+        denom: String,
+    },
+    // Enable or disable minting, burning and transferring a denom.
+    SetDenomEnabled {
+        denom: String,
+        enabled: bool,
     },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    // Query all members.
-    GetMembers {},
+    // Query members in pages ordered by ID.
+    GetMembers {
+        start_after: Option<String>, // If provided, start listing after this member ID
+        limit: Option<u32>,
+    },
     // Query a member by ID.
-    GetMember { id: String },
+    GetMember {
+        id: String,
+    },
+    // Query the outstanding supply of a denom minted by a member.
+    GetMemberSupply {
+        id: String,
+        denom: Option<String>, // If not provided, the contract denom
+    },
+    // Query the member that owns a kyc attribute.
+    GetMemberByKycAttr {
+        kyc_attr: String,
+    },
+    // Query the spending limits of an executor.
+    GetExecutorConfig {
+        id: String,
+    },
+    // Query the amount an executor can transfer from a holder.
+    GetAllowance {
+        holder: String,
+        executor: String,
+        denom: Option<String>, // If not provided, the contract denom
+    },
+    // Query a member's mint by bank deposit reference.
+    GetMintByReference {
+        member: String,
+        reference: String,
+    },
+    // Query the mint approval threshold of a member.
+    GetMintApproval {
+        id: String,
+    },
+    // Query a pending mint by ID.
+    GetPendingMint {
+        id: Uint128,
+    },
+    // Query pending mints in pages ordered by ID.
+    GetPendingMints {
+        start_after: Option<Uint128>, // If provided, start listing after this pending mint ID
+        limit: Option<u32>,
+    },
+    // Query an open redemption by member ID and reference.
+    GetRedemption {
+        member_id: String,
+        reference: String,
+    },
+    // Query the open redemptions of a member in pages ordered by reference.
+    GetRedemptions {
+        member_id: String,
+        start_after: Option<String>, // If provided, start listing after this reference
+        limit: Option<u32>,
+    },
+    // Query the compliance hold on an address.
+    GetFrozenAddress {
+        id: String,
+    },
+    // Query the force transfers out of an address in pages ordered by ID.
+    GetForceTransfers {
+        id: String,
+        start_after: Option<Uint128>, // If provided, start listing after this force transfer ID
+        limit: Option<u32>,
+    },
+    // Query a registered denom.
+    GetDenom {
+        denom: String,
+    },
+    // Query all registered denoms.
+    GetDenoms {},
+    // Query a join proposal by proposed member ID.
+    GetJoinProposal {
+        id: String,
+    },
     // Get contract state data.
     GetContractInfo {},
     // Get contract version data.
@@ -95,4 +318,41 @@ pub struct MigrateMsg {}
 #[serde(rename_all = "snake_case")]
 pub struct Members {
     pub members: Vec<MemberV2>,
+    // The member ID to start the next page after, if there are more members.
+    pub next_key: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MemberSupply {
+    pub id: Addr,
+    pub denom: String,
+    // The current outstanding supply of the denom minted by the member.
+    pub supply: Uint128,
+    // The max outstanding supply of the denom the member can mint (unlimited if not set).
+    pub max_supply: Option<Uint128>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ForceTransfers {
+    pub force_transfers: Vec<ForceTransfer>,
+    // The force transfer ID to start the next page after, if there are more force transfers.
+    pub next_key: Option<Uint128>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingMints {
+    pub pending_mints: Vec<PendingMint>,
+    // The pending mint ID to start the next page after, if there are more pending mints.
+    pub next_key: Option<Uint128>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Redemptions {
+    pub redemptions: Vec<Redemption>,
+    // The reference to start the next page after, if there are more redemptions.
+    pub next_key: Option<String>,
 }
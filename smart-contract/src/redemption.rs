@@ -0,0 +1,35 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{Addr, Storage, Uint128};
+use cosmwasm_storage::{Bucket, ReadonlyBucket};
+
+pub static REDEMPTION_KEY: &[u8] = b"redemption";
+
+/// Token a holder escrowed in the marker account to redeem for fiat at a member.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Redemption {
+    // The member that pays out the fiat.
+    pub member: Addr,
+    // The member's ID for the redemption.
+    pub reference: String,
+    // The account the token was escrowed from.
+    pub holder: Addr,
+    pub amount: Uint128,
+    pub denom: String,
+    // The block height the redemption was requested at.
+    pub created: Uint128,
+}
+
+// Open redemptions of a member, keyed by reference.
+pub fn redemptions<'a>(storage: &'a mut dyn Storage, member: &Addr) -> Bucket<'a, Redemption> {
+    Bucket::multilevel(storage, &[REDEMPTION_KEY, member.as_bytes()])
+}
+
+pub fn redemptions_read<'a>(
+    storage: &'a dyn Storage,
+    member: &Addr,
+) -> ReadonlyBucket<'a, Redemption> {
+    ReadonlyBucket::multilevel(storage, &[REDEMPTION_KEY, member.as_bytes()])
+}
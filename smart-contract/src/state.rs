@@ -39,6 +39,51 @@ pub struct StateV2 {
     pub denom: String,
     // Addresses that are authorized to transfer token by proxy.
     pub executors: Vec<String>,
+    // The number of blocks a member must wait between removal and finalized removal.
+    #[serde(default)]
+    pub removal_delay: Uint128,
+    // The proposed contract administrator account, pending acceptance.
+    #[serde(default)]
+    pub pending_admin: Option<PendingAdmin>,
+    // The percentage of total member weight that must vote 'yes' to approve a join proposal.
+    #[serde(default = "default_quorum_pct")]
+    pub quorum_pct: Decimal,
+    // The number of blocks join proposal voting windows are open.
+    #[serde(default = "default_vote_duration")]
+    pub vote_duration: Uint128,
+    // The operation classes that are currently paused.
+    #[serde(default)]
+    pub paused: Vec<PausedScope>,
+}
+
+/// A class of operations that can be paused independently.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseScope {
+    // All transfers, including executor transfers.
+    Transfer,
+    // Executor transfers only.
+    ExecutorTransfer,
+    Mint,
+    Burn,
+}
+
+/// A paused operation class and the reason it was paused.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PausedScope {
+    pub scope: PauseScope,
+    pub reason: String,
+}
+
+/// A proposed contract administrator that has not yet accepted.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingAdmin {
+    // The proposed administrator account.
+    pub address: Addr,
+    // The block height after which the proposal can no longer be accepted.
+    pub expires_at: Option<Uint128>,
 }
 
 #[allow(deprecated)]
@@ -48,10 +93,23 @@ impl From<State> for StateV2 {
             admin: state.admin,
             denom: state.dcc_denom,
             executors: vec![],
+            removal_delay: Uint128::zero(),
+            pending_admin: None,
+            quorum_pct: state.quorum_pct,
+            vote_duration: state.vote_duration,
+            paused: vec![],
         }
     }
 }
 
+pub fn default_quorum_pct() -> Decimal {
+    Decimal::percent(67)
+}
+
+pub fn default_vote_duration() -> Uint128 {
+    Uint128::new(5000)
+}
+
 #[allow(deprecated)]
 pub fn migrate_state(
     deps: DepsMut,
@@ -126,6 +184,11 @@ mod tests {
                 admin: Addr::unchecked("id"),
                 denom: "test.dcc".to_string(),
                 executors: vec![],
+                removal_delay: Uint128::zero(),
+                pending_admin: None,
+                quorum_pct: Decimal::percent(67),
+                vote_duration: Uint128::new(5000),
+                paused: vec![],
             }
         );
 